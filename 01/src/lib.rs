@@ -1,15 +1,32 @@
 use std::cmp;
 
-fn main() {
-    let input = include_str!("input.txt");
+use aoc2023_common::{Answer, ParseError, Solution};
 
-    dbg!(part_one(input));
-    dbg!(part_two(input));
+type DigitMatch = Option<(usize, usize)>;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(lines: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(lines).into())
+    }
+
+    fn part_two(lines: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(lines).into())
+    }
 }
 
-fn part_one(input: &str) -> u32 {
-    input
-        .lines()
+fn part_one(lines: &[&str]) -> u32 {
+    lines
+        .iter()
         .map(|line| {
             let digits: Vec<_> = line.chars().filter_map(|char| char.to_digit(10)).collect();
 
@@ -21,34 +38,33 @@ fn part_one(input: &str) -> u32 {
         .sum()
 }
 
-fn part_two(input: &str) -> u32 {
+fn part_two(lines: &[&str]) -> u32 {
     let spelled_out_digits = [
         "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
     ];
     let regular_digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
 
-    let find_indices =
-        |list: [&str; 10], line: &str| -> (Option<(usize, usize)>, Option<(usize, usize)>) {
-            let mut matched_digits: Vec<_> = list
-                .into_iter()
-                .enumerate()
-                .flat_map(|(idx_digit, digit)| {
-                    line.match_indices(digit)
-                        .map(|(idx_line, _)| (idx_line, idx_digit))
-                        .collect::<Vec<_>>()
-                })
-                .collect();
-
-            matched_digits.sort_by(|(a, _), (b, _)| a.cmp(b));
-
-            (
-                matched_digits.first().copied(),
-                matched_digits.last().copied(),
-            )
-        };
-
-    input
-        .lines()
+    let find_indices = |list: [&str; 10], line: &str| -> (DigitMatch, DigitMatch) {
+        let mut matched_digits: Vec<_> = list
+            .into_iter()
+            .enumerate()
+            .flat_map(|(idx_digit, digit)| {
+                line.match_indices(digit)
+                    .map(|(idx_line, _)| (idx_line, idx_digit))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        matched_digits.sort_by_key(|(idx_line, _)| *idx_line);
+
+        (
+            matched_digits.first().copied(),
+            matched_digits.last().copied(),
+        )
+    };
+
+    lines
+        .iter()
         .map(|line| {
             let (spelled_first, spelled_last) = find_indices(spelled_out_digits, line);
             let (regular_first, regular_last) = find_indices(regular_digits, line);
@@ -63,7 +79,8 @@ fn part_two(input: &str) -> u32 {
                         cmp::Ordering::Equal => unreachable!(),
                     }
                 }
-                (None, None) => unreachable!(),
+                // No digit either way, which scores nothing, as in part one.
+                (None, None) => return 0,
             };
 
             let last = match (spelled_last, regular_last) {
@@ -76,6 +93,7 @@ fn part_two(input: &str) -> u32 {
                         cmp::Ordering::Equal => unreachable!(),
                     }
                 }
+                // Only reached when there is a first digit.
                 (None, None) => unreachable!(),
             };
 
@@ -95,7 +113,7 @@ pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet";
 
-        assert_eq!(142, part_one(test_input));
+        assert_eq!(142, part_one(&Day01::parse(test_input).unwrap()));
     }
 
     #[test]
@@ -108,13 +126,22 @@ xtwone3four
 zoneight234
 7pqrstsixteen";
 
-        assert_eq!(281, part_two(test_input));
+        assert_eq!(281, part_two(&Day01::parse(test_input).unwrap()));
     }
 
     #[test]
     fn test_part2_edgecase() {
         let test_input = "gtjckhq73495fq3";
 
-        assert_eq!(73, part_two(test_input));
+        assert_eq!(73, part_two(&Day01::parse(test_input).unwrap()));
+    }
+
+    #[test]
+    fn test_part2_no_digits() {
+        let test_input = "two1nine
+abc
+7pqrstsixteen";
+
+        assert_eq!(29 + 76, part_two(&Day01::parse(test_input).unwrap()));
     }
 }
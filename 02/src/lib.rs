@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    const fn max(self) -> u32 {
+        match self {
+            Self::Red => 12,
+            Self::Green => 13,
+            Self::Blue => 14,
+        }
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "blue" => Ok(Self::Blue),
+            _ => Err(ParseError::new(value, "unknown color")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    reveals: Vec<(u32, Color)>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (game, sets) = parse::split_once(line, ":")?;
+        let id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new(game, "expected `Game <id>`, found"))
+            .and_then(|id| parse::number::<u32>(id.trim()))?;
+
+        let reveals = sets
+            .split(';')
+            .flat_map(|set| set.split(','))
+            .map(|reveal| {
+                let (amount, color) = parse::split_once(reveal.trim(), " ")?;
+                let color = Color::try_from(color.trim())?;
+                let amount = parse::number::<u32>(amount.trim())?;
+
+                Ok((amount, color))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { id, reveals })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.lines().map(Game::try_from).collect()
+    }
+
+    fn part_one(games: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(games).into())
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(games).into())
+    }
+}
+
+fn part_one(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| {
+            game.reveals
+                .iter()
+                .all(|(amount, color)| *amount <= color.max())
+        })
+        .map(|game| game.id)
+        .sum()
+}
+
+fn part_two(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| {
+            let mut minimums = HashMap::with_capacity(3);
+
+            for (amount, color) in game.reveals.iter() {
+                let current_min = minimums.get(color).unwrap_or(&0);
+
+                if amount > current_min {
+                    minimums.insert(*color, *amount);
+                }
+            }
+
+            // A color that was never revealed needs no cubes at all.
+            minimums.get(&Color::Red).unwrap_or(&0)
+                * minimums.get(&Color::Green).unwrap_or(&0)
+                * minimums.get(&Color::Blue).unwrap_or(&0)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        assert_eq!(8, part_one(&Day02::parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        assert_eq!(
+            48 + 12 + 1560 + 630 + 36,
+            part_two(&Day02::parse(input).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 purple";
+
+        let err = Day02::solve(input, Part::One).unwrap_err();
+
+        assert_eq!(
+            "day 02, line 2, column 19: unknown color `purple`",
+            err.to_string()
+        );
+
+        let err = Day02::solve("Game 1: 3 blue, 4", Part::Two).unwrap_err();
+
+        assert_eq!(
+            "day 02, line 1, column 17: expected ` ` in `4`",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use aoc2023_common::{
+    grid::{Grid, Point, Run},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            digit if digit.is_ascii_digit() => Ok(Self::Digit(digit as u32 - '0' as u32)),
+            symbol if symbol.is_ascii_punctuation() => Ok(Self::Symbol(symbol)),
+            _ => Err(ParseError::unanchored(value, "unknown cell")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PartNumber {
+    run: Run,
+    value: u32,
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<Cell>,
+    numbers: Vec<PartNumber>,
+}
+
+impl Schematic {
+    fn symbols_around<'a>(
+        &'a self,
+        number: &'a PartNumber,
+    ) -> impl Iterator<Item = (Point, char)> + 'a {
+        self.grid
+            .around(&number.run)
+            .filter_map(|point| match self.grid[point] {
+                Cell::Symbol(symbol) => Some((point, symbol)),
+                _ => None,
+            })
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse(input, Cell::try_from)?;
+
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let numbers = grid
+            .runs(|cell| matches!(cell, Cell::Digit(_)))
+            .into_iter()
+            .map(|run| {
+                let value = run.points().try_fold(0u32, |acc, point| match grid[point] {
+                    Cell::Digit(digit) => acc.checked_mul(10)?.checked_add(digit),
+                    _ => Some(acc),
+                });
+
+                // Every cell is ASCII, so columns are byte offsets too.
+                let digits = &lines[run.start.y][run.start.x..run.start.x + run.len];
+
+                value
+                    .map(|value| PartNumber { run, value })
+                    .ok_or_else(|| ParseError::new(digits, "part number overflows u32 at"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part_one(schematic: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(schematic).into())
+    }
+
+    fn part_two(schematic: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(schematic).into())
+    }
+}
+
+fn part_one(schematic: &Schematic) -> u32 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| schematic.symbols_around(number).next().is_some())
+        .map(|number| number.value)
+        .sum()
+}
+
+fn part_two(schematic: &Schematic) -> u32 {
+    let mut gears: HashMap<Point, Vec<u32>> = HashMap::new();
+
+    for number in schematic.numbers.iter() {
+        for (point, symbol) in schematic.symbols_around(number) {
+            if symbol == '*' {
+                gears.entry(point).or_default().push(number.value);
+            }
+        }
+    }
+
+    // A gear is any `*` adjacent to exactly two part numbers.
+    gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] * numbers[1])
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    static INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(4361, part_one(&Day03::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(467835, part_two(&Day03::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_number_at_end_of_row() {
+        let input = "...*
+..12
+....";
+
+        assert_eq!(12, part_one(&Day03::parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 03, line 2, column 3: unknown cell `x`",
+            Day03::solve("....\n..x.", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 03, line 2, column 2: part number overflows u32 at `4294967296`",
+            Day03::solve("............\n.4294967296*", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(Day03::solve("...........\n4294967295*", Part::One).is_ok());
+    }
+}
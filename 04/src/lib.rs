@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    numbers_you_have: HashSet<u32>,
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (_, numbers) = parse::split_once(line, ":")?;
+        let (winning_numbers, numbers_you_have) = parse::split_once(numbers, "|")?;
+
+        let winning_numbers = winning_numbers
+            .split_whitespace()
+            .map(parse::number::<u32>)
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        let numbers_you_have = numbers_you_have
+            .split_whitespace()
+            .map(parse::number::<u32>)
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        Ok(Self {
+            winning_numbers,
+            numbers_you_have,
+        })
+    }
+}
+
+impl Card {
+    fn winning_numbers_you_have(&self) -> usize {
+        self.numbers_you_have
+            .intersection(&self.winning_numbers)
+            .count()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.lines().map(Card::try_from).collect()
+    }
+
+    fn part_one(cards: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(cards).into())
+    }
+
+    fn part_two(cards: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(cards).into())
+    }
+}
+
+fn part_one(cards: &[Card]) -> u32 {
+    cards.iter().fold(0, |acc, card| {
+        let winning_numbers_you_have = card.winning_numbers_you_have();
+
+        if winning_numbers_you_have > 0 {
+            acc + 2_u32.pow(winning_numbers_you_have as u32 - 1)
+        } else {
+            acc
+        }
+    })
+}
+
+#[inline(always)]
+fn rec_tally_copies(cards: &HashMap<usize, HashSet<usize>>, card: usize) -> u32 {
+    // Copies past the end of the table don't exist, so they are never won.
+    let Some(copies) = cards.get(&card) else {
+        return 0;
+    };
+
+    if copies.is_empty() {
+        return 1;
+    }
+
+    copies
+        .iter()
+        .fold(1, |acc, copy| acc + rec_tally_copies(cards, *copy))
+}
+
+fn part_two(scratchcards: &[Card]) -> u32 {
+    let mut cards: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+    for (index, card) in scratchcards.iter().enumerate() {
+        let winning_numbers_you_have = card.winning_numbers_you_have();
+
+        let card_number = index + 1;
+        let copies =
+            ((card_number + 1)..=(winning_numbers_you_have + card_number)).collect::<HashSet<_>>();
+
+        cards.insert(card_number, copies);
+    }
+
+    cards
+        .iter()
+        .fold(0, |acc, (card, _)| acc + rec_tally_copies(&cards, *card))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        assert_eq!(13, part_one(&Day04::parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        assert_eq!(30, part_two(&Day04::parse(input).unwrap()));
+
+        // Card 2 would win a copy of card 3, which doesn't exist.
+        assert_eq!(
+            1 + 2,
+            part_two(&Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3").unwrap())
+        );
+        assert_eq!(1, part_two(&Day04::parse("Card 1: 1 2 | 1 2").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61   61 30 68 82 17 32 24 19";
+
+        assert_eq!(
+            "day 04, line 2, column 8: expected `|` in ` 13 32 20 16 61   61 30 68 82 17 32 24 19`",
+            Day04::solve(input, Part::One).unwrap_err().to_string()
+        );
+
+        assert_eq!(
+            "day 04, line 1, column 21: invalid number `8a`",
+            Day04::solve("Card 1: 41 48 83 86 8a | 83 86", Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use std::{collections::VecDeque, fmt, str::FromStr};
+
+use aoc2023_common::{parse, ParseError};
+
+use crate::{
+    mapper::{ConversionInstruction, Mapper},
+    piecewise::PiecewiseMap,
+};
+
+/// One `<source>-to-<destination> map:` block of the almanac.
+#[derive(Debug)]
+pub struct CategoryMap<'a> {
+    pub source: &'a str,
+    pub destination: &'a str,
+    pub mapper: Mapper,
+    /// 1-based line of the header within the almanac, or 0 when parsed on its own.
+    pub line: usize,
+}
+
+impl CategoryMap<'_> {
+    /// 1-based line of the instruction at `index`, the lines right after the header.
+    pub fn instruction_line(&self, index: usize) -> usize {
+        self.line + 1 + index
+    }
+}
+
+impl<'a> TryFrom<&'a str> for CategoryMap<'a> {
+    type Error = ParseError;
+
+    fn try_from(block: &'a str) -> Result<Self, Self::Error> {
+        let mut lines = block.lines();
+        let header = lines.next().unwrap_or(block);
+
+        let (source, destination) = header
+            .strip_suffix(" map:")
+            .ok_or_else(|| {
+                ParseError::new(header, "expected `<source>-to-<destination> map:`, found")
+            })
+            .and_then(|categories| parse::split_once(categories, "-to-"))?;
+
+        for category in [source, destination] {
+            if category.is_empty() || category.contains(char::is_whitespace) {
+                return Err(ParseError::new(category, "invalid category name"));
+            }
+        }
+
+        let lines = lines.collect::<Vec<_>>();
+
+        let conversion_instructions = lines
+            .iter()
+            .map(|line| ConversionInstruction::from_str(line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mapper = Mapper::try_from(conversion_instructions).map_err(|overlap| {
+            ParseError::new(
+                lines[overlap.later],
+                format!("source range overlaps `{}` in", lines[overlap.earlier]),
+            )
+        })?;
+
+        Ok(Self {
+            source,
+            destination,
+            mapper,
+            line: 0,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    UnknownCategory(String),
+    NoPath { from: String, to: String },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCategory(category) => write!(f, "unknown category `{category}`"),
+            Self::NoPath { from, to } => write!(f, "no conversion from `{from}` to `{to}`"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// The almanac's maps as a graph of categories, in whatever order they appeared.
+#[derive(Debug, Default)]
+pub struct Categories<'a> {
+    maps: Vec<CategoryMap<'a>>,
+}
+
+impl<'a> From<Vec<CategoryMap<'a>>> for Categories<'a> {
+    fn from(maps: Vec<CategoryMap<'a>>) -> Self {
+        Self { maps }
+    }
+}
+
+impl<'a> Categories<'a> {
+    /// Parses the map blocks of an almanac; anything before the first header is ignored.
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        input
+            .split_terminator("\n\n")
+            .filter(|block| !block.starts_with("seeds:"))
+            .map(|block| {
+                let offset = block.as_ptr() as usize - input.as_ptr() as usize;
+                let line = input[..offset].matches('\n').count() + 1;
+
+                CategoryMap::try_from(block).map(|map| CategoryMap { line, ..map })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+
+    pub fn maps(&self) -> &[CategoryMap<'a>] {
+        &self.maps
+    }
+
+    fn contains(&self, category: &str) -> bool {
+        self.maps
+            .iter()
+            .any(|map| map.source == category || map.destination == category)
+    }
+
+    /// The shortest chain of maps converting `from` into `to`.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<&CategoryMap<'a>>, ConversionError> {
+        for category in [from, to] {
+            if !self.contains(category) {
+                return Err(ConversionError::UnknownCategory(category.to_string()));
+            }
+        }
+
+        // Breadth-first search, remembering which map first reached each category.
+        let mut reached_by: Vec<(&str, Option<usize>)> = vec![(from, None)];
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+
+            for (idx, map) in self.maps.iter().enumerate() {
+                if map.source == category
+                    && !reached_by.iter().any(|(seen, _)| *seen == map.destination)
+                {
+                    reached_by.push((map.destination, Some(idx)));
+                    queue.push_back(map.destination);
+                }
+            }
+        }
+
+        let mut path = vec![];
+        let mut category = to;
+
+        while category != from {
+            let idx = reached_by
+                .iter()
+                .find(|(seen, _)| *seen == category)
+                .and_then(|(_, idx)| *idx)
+                .ok_or_else(|| ConversionError::NoPath {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                })?;
+
+            path.push(&self.maps[idx]);
+            category = self.maps[idx].source;
+        }
+
+        path.reverse();
+
+        Ok(path)
+    }
+
+    /// The maps from `from` to `to`, composed into a single function.
+    pub fn chain(&self, from: &str, to: &str) -> Result<PiecewiseMap, ConversionError> {
+        let path = self.path(from, to)?;
+
+        Ok(PiecewiseMap::chain(path.into_iter().map(|map| &map.mapper)))
+    }
+
+    pub fn convert(&self, value: u128, from: &str, to: &str) -> Result<u128, ConversionError> {
+        let path = self.path(from, to)?;
+
+        Ok(path
+            .into_iter()
+            .fold(value, |value, map| map.mapper.map(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MAPS: &str = "light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-light map:
+0 15 37
+
+temperature-to-humidity map:
+0 69 1
+1 0 69";
+
+    #[test]
+    fn test_path() {
+        let categories = Categories::parse(MAPS).unwrap();
+
+        let path = categories
+            .path("seed", "humidity")
+            .unwrap()
+            .into_iter()
+            .map(|map| map.destination)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["soil", "light", "temperature", "humidity"], path);
+        assert_eq!(
+            vec![1, 6, 10, 13],
+            categories
+                .maps()
+                .iter()
+                .map(|map| map.line)
+                .collect::<Vec<_>>()
+        );
+        assert!(categories.path("light", "light").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_convert() {
+        let categories = Categories::parse(MAPS).unwrap();
+
+        // 79 -> soil 81 -> light 81 -> temperature 49 -> humidity 50
+        assert_eq!(Ok(50), categories.convert(79, "seed", "humidity"));
+        assert_eq!(Ok(81), categories.convert(79, "seed", "light"));
+        assert_eq!(
+            categories.chain("seed", "humidity").unwrap().map(79),
+            categories.convert(79, "seed", "humidity").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        let categories = Categories::parse(MAPS).unwrap();
+
+        assert_eq!(
+            "no conversion from `humidity` to `seed`",
+            categories
+                .convert(46, "humidity", "seed")
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            Err(ConversionError::UnknownCategory("water".to_string())),
+            categories.convert(46, "seed", "water")
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Categories::parse("seed-to-soil\n50 98 2").unwrap_err();
+
+        assert_eq!(
+            "expected `<source>-to-<destination> map:`, found",
+            err.reason()
+        );
+        assert_eq!("seed-to-soil", err.text());
+
+        let err = Categories::parse("seed soil map:\n50 98 2").unwrap_err();
+
+        assert_eq!("expected `-to-` in", err.reason());
+        assert_eq!("seed soil", err.text());
+
+        let err = Categories::parse("seed-to- map:\n50 98 2").unwrap_err();
+
+        assert_eq!("invalid category name", err.reason());
+
+        let err = Categories::parse("seed-to-soil map:\n50 98 2\n0 99 5").unwrap_err();
+
+        assert_eq!("source range overlaps `50 98 2` in", err.reason());
+        assert_eq!("0 99 5", err.text());
+    }
+}
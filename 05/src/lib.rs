@@ -0,0 +1,364 @@
+mod category;
+mod mapper;
+mod piecewise;
+mod trace;
+mod validate;
+
+use std::ops::Range;
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+pub use category::{Categories, CategoryMap, ConversionError};
+pub use mapper::ConversionInstruction;
+pub use piecewise::{PiecewiseMap, Segment};
+pub use trace::{Applied, Hop, Trace};
+pub use validate::{validate, Finding, Issue};
+
+#[derive(Debug)]
+pub struct Almanac<'a> {
+    seeds: Vec<u128>,
+    categories: Categories<'a>,
+    seed_to_location: PiecewiseMap,
+}
+
+impl<'a> Almanac<'a> {
+    pub fn categories(&self) -> &Categories<'a> {
+        &self.categories
+    }
+
+    /// Converts `value` of category `from` into category `to`, through any maps in between.
+    pub fn convert(&self, value: u128, from: &str, to: &str) -> Result<u128, ConversionError> {
+        self.categories.convert(value, from, to)
+    }
+
+    /// The whole mapper chain, composed once so a lookup is a single binary search.
+    pub fn seed_to_location(&self) -> &PiecewiseMap {
+        &self.seed_to_location
+    }
+
+    /// How `seed` travels from the seed category to its location.
+    pub fn trace(&self, seed: u128) -> Trace<'_> {
+        // unwrap: Parsing an almanac fails without a path from seeds to locations.
+        let path = self.categories.path("seed", "location").unwrap();
+
+        Trace::new(seed, &path)
+    }
+
+    /// Every seed that ends up at `location`, in ascending order.
+    pub fn seeds_for(&self, location: u128) -> Vec<u128> {
+        self.seed_to_location.inverse(location)
+    }
+
+    /// The seed within `seed_ranges` with the lowest location, as `(seed, location)`.
+    pub fn lowest_seed(&self, seed_ranges: &[Range<u128>]) -> Option<(u128, u128)> {
+        self.seed_to_location.lowest(seed_ranges)
+    }
+
+    fn seed_ranges(&self) -> Vec<Range<u128>> {
+        let seed_ranges = self
+            .seeds
+            .chunks_exact(2)
+            // Parsing checked that seeds come in non-empty pairs that do not overflow.
+            .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+            .collect();
+
+        mapper::normalize(seed_ranges)
+    }
+
+    fn location(&self, seed: u128) -> u128 {
+        self.seed_to_location.map(seed)
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let seeds_line = input.lines().next().unwrap_or(input);
+
+        let tokens = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::new(seeds_line, "expected `seeds:`, found"))?
+            .split_whitespace()
+            .collect::<Vec<_>>();
+
+        let seeds = tokens
+            .iter()
+            .copied()
+            .map(parse::number::<u128>)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if seeds.is_empty() {
+            return Err(ParseError::new(seeds_line, "expected at least one seed in"));
+        }
+
+        // Part two reads the seeds as pairs of range start and length.
+        if tokens.len() % 2 == 1 {
+            return Err(ParseError::new(
+                tokens[tokens.len() - 1],
+                "expected a range length after",
+            ));
+        }
+
+        for (pair, range) in tokens.chunks_exact(2).zip(seeds.chunks_exact(2)) {
+            if range[1] == 0 {
+                return Err(ParseError::new(
+                    pair[1],
+                    "expected a range length above 0, found",
+                ));
+            }
+
+            if range[0].checked_add(range[1]).is_none() {
+                let start = pair[0].as_ptr() as usize - seeds_line.as_ptr() as usize;
+                let end = pair[1].as_ptr() as usize - seeds_line.as_ptr() as usize + pair[1].len();
+
+                return Err(ParseError::new(
+                    &seeds_line[start..end],
+                    "range overflows u128 in",
+                ));
+            }
+        }
+
+        let categories = Categories::parse(input)?;
+
+        // The puzzle itself always needs a way from seeds to locations.
+        let seed_to_location = categories
+            .chain("seed", "location")
+            .map_err(|err| ParseError::unanchored("", err.to_string()))?;
+
+        Ok(Almanac {
+            seeds,
+            categories,
+            seed_to_location,
+        })
+    }
+
+    fn part_one(almanac: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(almanac).into())
+    }
+
+    fn part_two(almanac: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(almanac).into())
+    }
+}
+
+fn part_one(almanac: &Almanac) -> u128 {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.location(*seed))
+        .min()
+        // unwrap: Parsing rejects almanacs without seeds.
+        .unwrap()
+}
+
+fn part_two(almanac: &Almanac) -> u128 {
+    let locations = mapper::normalize(
+        almanac
+            .seed_ranges()
+            .into_iter()
+            .flat_map(|range| almanac.seed_to_location.map_range(range))
+            .collect(),
+    );
+
+    // Normalized ranges are sorted, so the lowest location starts the first one.
+    // unwrap: Parsing rejects empty seed ranges, and every seed has a location.
+    locations.first().unwrap().start
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    static INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(35, part_one(&Day05::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(46, part_two(&Day05::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_composed_chain() {
+        let almanac = Day05::parse(INPUT).unwrap();
+
+        for seed in 0..120 {
+            let location = almanac
+                .categories()
+                .maps()
+                .iter()
+                .fold(seed, |value, map| map.mapper.map(value));
+
+            assert_eq!(location, almanac.location(seed), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let almanac = Day05::parse(INPUT).unwrap();
+        let trace = almanac.trace(79);
+
+        assert_eq!(82, trace.result());
+        assert_eq!(almanac.location(13), almanac.trace(13).result());
+
+        assert_eq!(
+            "seed         79
+soil         81  seed-to-soil, line 5: 52 50 48
+fertilizer   81  soil-to-fertilizer: identity
+water        81  fertilizer-to-water: identity
+light        74  water-to-light, line 20: 18 25 70
+temperature  78  light-to-temperature, line 25: 68 64 13
+humidity     78  temperature-to-humidity: identity
+location     82  humidity-to-location, line 32: 60 56 37",
+            trace.to_string()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(INPUT).is_empty());
+    }
+
+    #[test]
+    fn test_reordered_blocks() {
+        let mut blocks = INPUT.split("\n\n").collect::<Vec<_>>();
+        blocks[1..].reverse();
+
+        let reordered = blocks.join("\n\n");
+        let almanac = Day05::parse(&reordered).unwrap();
+
+        assert_eq!(35, part_one(&almanac));
+        assert_eq!(46, part_two(&almanac));
+        assert_eq!(Ok(53), almanac.convert(14, "seed", "fertilizer"));
+        assert_eq!(Ok(27), almanac.convert(53, "soil", "water"));
+    }
+
+    #[test]
+    fn test_seeds_for() {
+        let almanac = Day05::parse(INPUT).unwrap();
+
+        assert_eq!(vec![82], almanac.seeds_for(46));
+        assert_eq!(Some((82, 46)), almanac.lowest_seed(&almanac.seed_ranges()));
+
+        for location in 0..120 {
+            assert!(almanac
+                .seeds_for(location)
+                .into_iter()
+                .all(|seed| almanac.location(seed) == location));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 05, line 1, column 1: expected `seeds:`, found `79 14 55 13`",
+            Day05::solve("79 14 55 13", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let without_seeds = INPUT.replace("seeds: 79 14 55 13", "seeds: ");
+
+        assert_eq!(
+            "day 05, line 1, column 1: expected at least one seed in `seeds: `",
+            Day05::solve(&without_seeds, Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let odd_seeds = INPUT.replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+
+        assert_eq!(
+            "day 05, line 1, column 14: expected a range length after `55`",
+            Day05::solve(&odd_seeds, Part::One).unwrap_err().to_string()
+        );
+
+        let empty_range = INPUT.replace("seeds: 79 14 55 13", "seeds: 79 0");
+
+        assert_eq!(
+            "day 05, line 1, column 11: expected a range length above 0, found `0`",
+            Day05::solve(&empty_range, Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let overflowing = INPUT.replace(
+            "seeds: 79 14 55 13",
+            "seeds: 79 14 340282366920938463463374607431768211455 13",
+        );
+
+        assert_eq!(
+            "day 05, line 1, column 14: range overflows u128 in `340282366920938463463374607431768211455 13`",
+            Day05::solve(&overflowing, Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let without_humidity = INPUT.replace("humidity-to-location", "humidity-to-place");
+
+        assert_eq!(
+            "day 05: unknown category `location`",
+            Day05::solve(&without_humidity, Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let overlapping = INPUT.replace("0 69 1", "0 68 2");
+
+        assert_eq!(
+            "day 05, line 29, column 1: source range overlaps `0 68 2` in `1 0 69`",
+            Day05::solve(&overlapping, Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let truncated = INPUT.replace("0 69 1", "0 69");
+
+        assert_eq!(
+            "day 05, line 28, column 1: expected `<destination> <source> <length>`, found `0 69`",
+            Day05::solve(&truncated, Part::One).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::{fmt, ops::Range, str::FromStr};
+
+use aoc2023_common::{parse, ParseError};
+
+use crate::validate::Issue;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionInstruction {
+    destination_range_start: u128,
+    source_range_start: u128,
+    range_length: u128,
+}
+
+impl FromStr for ConversionInstruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let instruction = Self::parse_unchecked(line)?;
+
+        match instruction.issue() {
+            Some(issue) => Err(ParseError::new(line, format!("{issue} in"))),
+            None => Ok(instruction),
+        }
+    }
+}
+
+impl ConversionInstruction {
+    /// Reads the three numbers of an instruction without checking that they make sense.
+    pub fn parse_unchecked(line: &str) -> Result<Self, ParseError> {
+        let mut numbers = line.split_whitespace();
+        let mut next_number = || {
+            numbers
+                .next()
+                .ok_or_else(|| {
+                    ParseError::new(line, "expected `<destination> <source> <length>`, found")
+                })
+                .and_then(parse::number::<u128>)
+        };
+
+        let instruction = Self {
+            destination_range_start: next_number()?,
+            source_range_start: next_number()?,
+            range_length: next_number()?,
+        };
+
+        match numbers.next() {
+            Some(extra) => Err(ParseError::new(extra, "unexpected trailing number")),
+            None => Ok(instruction),
+        }
+    }
+
+    /// What makes this instruction unusable on its own, if anything.
+    pub fn issue(&self) -> Option<Issue> {
+        if self.range_length == 0 {
+            return Some(Issue::ZeroLength);
+        }
+
+        let source_end = self.source_range_start.checked_add(self.range_length);
+        let destination_end = self.destination_range_start.checked_add(self.range_length);
+
+        match (source_end, destination_end) {
+            (Some(_), Some(_)) => None,
+            _ => Some(Issue::Overflow),
+        }
+    }
+
+    pub fn source_range(&self) -> Range<u128> {
+        self.source_range_start..self.source_range_start + self.range_length
+    }
+
+    pub fn convert(&self, source: u128) -> u128 {
+        self.destination_range_start + (source - self.source_range_start)
+    }
+}
+
+impl fmt::Display for ConversionInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_range_start, self.source_range_start, self.range_length
+        )
+    }
+}
+
+/// Sorts `ranges` and merges the ones that overlap or touch, dropping empty ones.
+pub fn normalize(mut ranges: Vec<Range<u128>>) -> Vec<Range<u128>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<u128>> = Vec::with_capacity(ranges.len());
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Every pair of instructions, as `(earlier, later)` indices, whose source ranges overlap.
+pub fn overlaps(instructions: &[ConversionInstruction]) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+
+    for (later, instruction) in instructions.iter().enumerate() {
+        let source = instruction.source_range();
+
+        for (earlier, other) in instructions[..later].iter().enumerate() {
+            let other = other.source_range();
+
+            if source.start < other.end && other.start < source.end {
+                pairs.push((earlier, later));
+            }
+        }
+    }
+
+    pairs
+}
+
+/// The values between the lowest and highest source that no instruction covers.
+pub fn gaps(instructions: &[ConversionInstruction]) -> Vec<Range<u128>> {
+    normalize(
+        instructions
+            .iter()
+            .map(ConversionInstruction::source_range)
+            .collect(),
+    )
+    .windows(2)
+    .map(|pair| pair[0].end..pair[1].start)
+    .collect()
+}
+
+/// Two instructions of a [`Mapper`] claim the same source values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap {
+    pub earlier: usize,
+    pub later: usize,
+}
+
+#[derive(Debug)]
+pub struct Mapper(Vec<ConversionInstruction>);
+
+impl TryFrom<Vec<ConversionInstruction>> for Mapper {
+    type Error = Overlap;
+
+    fn try_from(value: Vec<ConversionInstruction>) -> Result<Self, Self::Error> {
+        match overlaps(&value).first() {
+            Some(&(earlier, later)) => Err(Overlap { earlier, later }),
+            None => Ok(Self(value)),
+        }
+    }
+}
+
+impl Mapper {
+    pub fn instructions(&self) -> &[ConversionInstruction] {
+        &self.0
+    }
+
+    /// The instruction covering `source` and its index, if any.
+    pub fn find(&self, source: u128) -> Option<(usize, &ConversionInstruction)> {
+        self.0
+            .iter()
+            .enumerate()
+            .find(|(_, instruction)| instruction.source_range().contains(&source))
+    }
+
+    pub fn map(&self, source: u128) -> u128 {
+        self.find(source)
+            .map_or(source, |(_, instruction)| instruction.convert(source))
+    }
+
+    /// Maps every value of `range` at once, splitting it wherever it crosses the
+    /// boundary of a [`ConversionInstruction`]. Values not covered by any
+    /// instruction pass through unchanged, just like in [`Mapper::map`].
+    pub fn map_range(&self, range: Range<u128>) -> Vec<Range<u128>> {
+        let mut unmapped = vec![range];
+        let mut mapped = vec![];
+
+        // Source ranges never overlap, so each value is mapped by at most one instruction.
+        for instruction in self.0.iter() {
+            let source = instruction.source_range();
+
+            unmapped = unmapped
+                .into_iter()
+                .flat_map(|range| {
+                    let overlap = range.start.max(source.start)..range.end.min(source.end);
+
+                    if overlap.is_empty() {
+                        return vec![range];
+                    }
+
+                    mapped.push(
+                        instruction.convert(overlap.start)
+                            ..instruction.convert(overlap.end - 1) + 1,
+                    );
+
+                    vec![range.start..overlap.start, overlap.end..range.end]
+                })
+                .filter(|range| !range.is_empty())
+                .collect();
+        }
+
+        mapped.extend(unmapped);
+
+        normalize(mapped)
+    }
+
+    /// [`Mapper::map_range`] over a set of ranges.
+    pub fn map_ranges(&self, ranges: &[Range<u128>]) -> Vec<Range<u128>> {
+        normalize(
+            ranges
+                .iter()
+                .flat_map(|range| self.map_range(range.clone()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instructions(instructions: &[(u128, u128, u128)]) -> Vec<ConversionInstruction> {
+        instructions
+            .iter()
+            .map(|(destination, source, length)| ConversionInstruction {
+                destination_range_start: *destination,
+                source_range_start: *source,
+                range_length: *length,
+            })
+            .collect()
+    }
+
+    fn mapper(instructions: &[(u128, u128, u128)]) -> Mapper {
+        Mapper::try_from(self::instructions(instructions)).unwrap()
+    }
+
+    #[test]
+    fn test_map_range_splits_at_boundaries() {
+        // seed-to-soil map of the example.
+        let mapper = mapper(&[(50, 98, 2), (52, 50, 48)]);
+
+        assert_eq!(vec![81..95], mapper.map_range(79..93));
+        assert_eq!(vec![50..52, 98..101], mapper.map_range(96..101));
+        assert_eq!(vec![45..100], mapper.map_range(45..100));
+    }
+
+    #[test]
+    fn test_overlapping_instructions_rejected() {
+        let overlapping = instructions(&[(0, 10, 5), (50, 30, 5), (100, 12, 5)]);
+
+        assert_eq!(vec![(0, 2)], overlaps(&overlapping));
+        assert_eq!(
+            Overlap {
+                earlier: 0,
+                later: 2
+            },
+            Mapper::try_from(overlapping).unwrap_err()
+        );
+
+        // Touching ranges do not overlap.
+        assert!(Mapper::try_from(instructions(&[(0, 10, 5), (100, 15, 5)])).is_ok());
+    }
+
+    #[test]
+    fn test_instruction_issues() {
+        let parse = |line: &str| line.parse::<ConversionInstruction>().unwrap_err();
+
+        assert_eq!("zero-length range in", parse("50 98 0").reason());
+        assert_eq!(
+            "range overflows u128 in",
+            parse("0 340282366920938463463374607431768211455 2").reason()
+        );
+        assert_eq!(
+            "range overflows u128 in",
+            parse("340282366920938463463374607431768211455 0 2").reason()
+        );
+    }
+
+    #[test]
+    fn test_gaps() {
+        let instructions = instructions(&[(0, 20, 5), (0, 0, 10), (0, 10, 5)]);
+
+        assert_eq!(vec![15..20], gaps(&instructions));
+    }
+
+    #[test]
+    fn test_map_range_matches_map() {
+        let mapper = mapper(&[(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+
+        let expected = normalize(
+            (0..70)
+                .map(|seed| mapper.map(seed)..mapper.map(seed) + 1)
+                .collect(),
+        );
+
+        assert_eq!(expected, mapper.map_ranges(&[0..30, 30..70]));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            vec![0..7, 9..10],
+            normalize(vec![5..7, 9..10, 0..3, 3..3, 2..5])
+        );
+    }
+}
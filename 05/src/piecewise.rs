@@ -0,0 +1,399 @@
+use std::ops::Range;
+
+use crate::mapper::{self, Mapper};
+
+/// Values at or above this bound are outside of every almanac and map to themselves.
+pub const DOMAIN_END: u128 = u128::MAX;
+
+/// A run of consecutive source values that map onto consecutive destinations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub source: Range<u128>,
+    pub destination_start: u128,
+}
+
+impl Segment {
+    pub fn destination(&self) -> Range<u128> {
+        self.destination_start..self.destination_start + (self.source.end - self.source.start)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.source.start == self.destination_start
+    }
+
+    fn map(&self, source: u128) -> u128 {
+        self.destination_start + (source - self.source.start)
+    }
+
+    fn unmap(&self, destination: u128) -> u128 {
+        self.source.start + (destination - self.destination_start)
+    }
+}
+
+/// A whole [`Mapper`], or a chain of them, as one piecewise-linear function.
+///
+/// The segments are sorted, never overlap and cover every value of
+/// `0..DOMAIN_END`, identity passthrough included. Neighbouring segments that
+/// would continue each other are merged, so equal functions have equal segments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    segments: Vec<Segment>,
+}
+
+impl Default for PiecewiseMap {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<&Mapper> for PiecewiseMap {
+    fn from(mapper: &Mapper) -> Self {
+        // A mapper's source ranges never overlap, so its instructions are segments as is.
+        let mut claimed = mapper
+            .instructions()
+            .iter()
+            .map(|instruction| {
+                let source = instruction.source_range();
+
+                Segment {
+                    destination_start: instruction.convert(source.start),
+                    source,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        claimed.sort_by_key(|segment| segment.source.start);
+
+        let mut segments = Vec::with_capacity(claimed.len() * 2 + 1);
+        let mut next = 0;
+
+        for segment in claimed {
+            if next < segment.source.start {
+                segments.push(identity(next..segment.source.start));
+            }
+
+            next = segment.source.end;
+            segments.push(segment);
+        }
+
+        if next < DOMAIN_END {
+            segments.push(identity(next..DOMAIN_END));
+        }
+
+        Self::normalized(segments)
+    }
+}
+
+fn identity(range: Range<u128>) -> Segment {
+    Segment {
+        destination_start: range.start,
+        source: range,
+    }
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self {
+            segments: vec![identity(0..DOMAIN_END)],
+        }
+    }
+
+    /// Folds a chain of mappers, applied first to last, into a single map.
+    pub fn chain<'a>(mappers: impl IntoIterator<Item = &'a Mapper>) -> Self {
+        mappers
+            .into_iter()
+            .fold(Self::identity(), |map, mapper| map.then(&mapper.into()))
+    }
+
+    fn normalized(mut segments: Vec<Segment>) -> Self {
+        segments.sort_by_key(|segment| segment.source.start);
+
+        let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source.end == segment.source.start
+                        && last.destination().end == segment.destination_start =>
+                {
+                    last.source.end = segment.source.end;
+                }
+                _ => merged.push(segment),
+            }
+        }
+
+        Self { segments: merged }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    fn segment_index(&self, value: u128) -> usize {
+        self.segments
+            .partition_point(|segment| segment.source.end <= value)
+    }
+
+    pub fn map(&self, value: u128) -> u128 {
+        self.segments
+            .get(self.segment_index(value))
+            .map_or(value, |segment| segment.map(value))
+    }
+
+    /// The destination intervals of every value in `range`, normalized.
+    pub fn map_range(&self, range: Range<u128>) -> Vec<Range<u128>> {
+        let first = self.segment_index(range.start);
+
+        let ranges = self.segments[first.min(self.segments.len())..]
+            .iter()
+            .take_while(|segment| segment.source.start < range.end)
+            .map(|segment| {
+                let start = range.start.max(segment.source.start);
+                let end = range.end.min(segment.source.end);
+
+                segment.map(start)..segment.map(start) + (end - start)
+            })
+            .collect();
+
+        mapper::normalize(ranges)
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut segments = vec![];
+
+        for segment in self.segments.iter() {
+            let destination = segment.destination();
+            let first = next.segment_index(destination.start);
+
+            for next_segment in next.segments[first..]
+                .iter()
+                .take_while(|next_segment| next_segment.source.start < destination.end)
+            {
+                let start = destination.start.max(next_segment.source.start);
+                let end = destination.end.min(next_segment.source.end);
+                let source_start = segment.source.start + (start - destination.start);
+
+                segments.push(Segment {
+                    source: source_start..source_start + (end - start),
+                    destination_start: next_segment.map(start),
+                });
+            }
+        }
+
+        Self::normalized(segments)
+    }
+
+    /// Every value that maps to `destination`, in ascending order.
+    ///
+    /// Maps are not injective, so there may be several of them, or none at all.
+    pub fn inverse(&self, destination: u128) -> Vec<u128> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.destination().contains(&destination))
+            .map(|segment| segment.unmap(destination))
+            .collect()
+    }
+
+    /// Every interval of values that maps into `range`, normalized.
+    pub fn inverse_range(&self, range: Range<u128>) -> Vec<Range<u128>> {
+        let ranges = self
+            .segments
+            .iter()
+            .filter_map(|segment| {
+                let destination = segment.destination();
+                let start = range.start.max(destination.start);
+                let end = range.end.min(destination.end);
+
+                (start < end).then(|| segment.unmap(start)..segment.unmap(end - 1) + 1)
+            })
+            .collect();
+
+        mapper::normalize(ranges)
+    }
+
+    /// The value within `sources` that maps lowest, as `(source, destination)`.
+    ///
+    /// Segments are searched by ascending destination, so the search stops as
+    /// soon as no remaining segment can beat the best destination found.
+    pub fn lowest(&self, sources: &[Range<u128>]) -> Option<(u128, u128)> {
+        let mut by_destination = self.segments.iter().collect::<Vec<_>>();
+        by_destination.sort_by_key(|segment| segment.destination_start);
+
+        let mut lowest: Option<(u128, u128)> = None;
+
+        for segment in by_destination {
+            if lowest.is_some_and(|(_, destination)| destination <= segment.destination_start) {
+                break;
+            }
+
+            let source = sources
+                .iter()
+                .filter(|source| {
+                    source.start < segment.source.end && segment.source.start < source.end
+                })
+                .map(|source| source.start.max(segment.source.start))
+                .min();
+
+            if let Some(source) = source {
+                let destination = segment.map(source);
+
+                if lowest.is_none_or(|(_, lowest)| destination < lowest) {
+                    lowest = Some((source, destination));
+                }
+            }
+        }
+
+        lowest
+    }
+}
+
+impl Mapper {
+    /// Composes this mapper with `next`, which is applied to its output.
+    pub fn compose(&self, next: &Mapper) -> PiecewiseMap {
+        PiecewiseMap::from(self).then(&next.into())
+    }
+
+    /// Every source that maps to `destination`, passthrough included.
+    pub fn inverse(&self, destination: u128) -> Vec<u128> {
+        PiecewiseMap::from(self).inverse(destination)
+    }
+
+    /// Every source interval that maps into `range`, passthrough included.
+    pub fn inverse_range(&self, range: Range<u128>) -> Vec<Range<u128>> {
+        PiecewiseMap::from(self).inverse_range(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapper::ConversionInstruction;
+
+    fn mapper(instructions: &[&str]) -> Mapper {
+        instructions
+            .iter()
+            .map(|line| line.parse::<ConversionInstruction>().unwrap())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_from_mapper() {
+        // seed-to-soil map of the example.
+        let map = PiecewiseMap::from(&mapper(&["50 98 2", "52 50 48"]));
+
+        assert_eq!(
+            vec![
+                identity(0..50),
+                Segment {
+                    source: 50..98,
+                    destination_start: 52,
+                },
+                Segment {
+                    source: 98..100,
+                    destination_start: 50,
+                },
+                identity(100..DOMAIN_END),
+            ],
+            map.segments()
+        );
+    }
+
+    #[test]
+    fn test_from_mapper_merged() {
+        // The last instruction continues the identity passthrough before it.
+        let map = PiecewiseMap::from(&mapper(&["0 10 5", "103 15 2", "5 5 5"]));
+
+        assert_eq!(
+            vec![
+                identity(0..10),
+                Segment {
+                    source: 10..15,
+                    destination_start: 0,
+                },
+                Segment {
+                    source: 15..17,
+                    destination_start: 103,
+                },
+                identity(17..DOMAIN_END),
+            ],
+            map.segments()
+        );
+    }
+
+    #[test]
+    fn test_compose_matches_sequential_mapping() {
+        let first = mapper(&["0 15 37", "37 52 2", "39 0 15"]);
+        let second = mapper(&["49 53 8", "0 11 42", "42 0 7", "57 7 4"]);
+        let composed = first.compose(&second);
+
+        for value in 0..120 {
+            assert_eq!(second.map(first.map(value)), composed.map(value), "{value}");
+        }
+
+        assert!(composed
+            .segments()
+            .windows(2)
+            .all(|pair| pair[0].source.end == pair[1].source.start));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let seed_to_soil = mapper(&["50 98 2", "52 50 48"]);
+
+        assert_eq!(vec![98], seed_to_soil.inverse(50));
+        assert_eq!(vec![99], seed_to_soil.inverse(51));
+        assert_eq!(vec![50], seed_to_soil.inverse(52));
+        assert_eq!(vec![10], seed_to_soil.inverse(10));
+        assert_eq!(vec![100], seed_to_soil.inverse(100));
+
+        // 0 passes through unchanged and is also where 10 is sent.
+        let folding = mapper(&["0 10 5"]);
+
+        assert_eq!(vec![0, 10], folding.inverse(0));
+        assert_eq!(Vec::<u128>::new(), folding.inverse(12));
+    }
+
+    #[test]
+    fn test_inverse_range() {
+        let seed_to_soil = mapper(&["50 98 2", "52 50 48"]);
+
+        assert_eq!(vec![45..50, 98..100], seed_to_soil.inverse_range(45..52));
+        assert_eq!(vec![98..100], seed_to_soil.inverse_range(50..52));
+        assert_eq!(vec![100..110], seed_to_soil.inverse_range(100..110));
+
+        let composed = seed_to_soil.compose(&mapper(&["0 15 37", "37 52 2", "39 0 15"]));
+
+        for destination in 0..120 {
+            let sources = composed.inverse_range(destination..destination + 1);
+            let expanded = sources.into_iter().flatten().collect::<Vec<_>>();
+
+            assert_eq!(composed.inverse(destination), expanded);
+            assert!(expanded
+                .iter()
+                .all(|source| composed.map(*source) == destination));
+        }
+    }
+
+    #[test]
+    fn test_lowest() {
+        let map = PiecewiseMap::from(&mapper(&["50 98 2", "52 50 48"]));
+
+        assert_eq!(Some((98, 50)), map.lowest(&[60..70, 95..100]));
+        assert_eq!(Some((60, 62)), map.lowest(&[60..70, 200..210]));
+        assert_eq!(Some((5, 5)), map.lowest(&[5..6, 98..99]));
+        assert_eq!(None, map.lowest(&[]));
+    }
+
+    #[test]
+    fn test_identity() {
+        let map = PiecewiseMap::chain([]);
+
+        assert_eq!(PiecewiseMap::identity(), map);
+        assert_eq!(42, map.map(42));
+        assert_eq!(u128::MAX, map.map(u128::MAX));
+        assert_eq!(vec![3..9], map.map_range(3..9));
+    }
+}
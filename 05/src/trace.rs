@@ -0,0 +1,109 @@
+use std::fmt;
+
+use crate::{category::CategoryMap, mapper::ConversionInstruction};
+
+/// The instruction that moved a value into the next category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Applied<'a> {
+    /// 1-based line of the instruction within the almanac.
+    pub line: usize,
+    pub instruction: &'a ConversionInstruction,
+}
+
+/// A value arriving in `category`, through the `from`-to-`category` map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hop<'a> {
+    pub from: &'a str,
+    pub category: &'a str,
+    pub value: u128,
+    /// `None` when no instruction covered the value and it passed through unchanged.
+    pub applied: Option<Applied<'a>>,
+}
+
+impl Hop<'_> {
+    pub fn map(&self) -> String {
+        format!("{}-to-{}", self.from, self.category)
+    }
+}
+
+/// How one seed travelled through the almanac, map by map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace<'a> {
+    pub seed: u128,
+    pub hops: Vec<Hop<'a>>,
+}
+
+impl<'a> Trace<'a> {
+    pub fn new(seed: u128, path: &[&'a CategoryMap<'_>]) -> Self {
+        let mut value = seed;
+
+        let hops = path
+            .iter()
+            .map(|map| {
+                let applied = map.mapper.find(value).map(|(index, instruction)| {
+                    value = instruction.convert(value);
+
+                    Applied {
+                        line: map.instruction_line(index),
+                        instruction,
+                    }
+                });
+
+                Hop {
+                    from: map.source,
+                    category: map.destination,
+                    value,
+                    applied,
+                }
+            })
+            .collect();
+
+        Self { seed, hops }
+    }
+
+    /// The value in the last category of the trace.
+    pub fn result(&self) -> u128 {
+        self.hops.last().map_or(self.seed, |hop| hop.value)
+    }
+}
+
+impl fmt::Display for Trace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.hops.first().map_or("seed", |hop| hop.from);
+
+        let category_width = self
+            .hops
+            .iter()
+            .map(|hop| hop.category.len())
+            .chain([start.len()])
+            .max()
+            .unwrap_or(0);
+
+        let value_width = self
+            .hops
+            .iter()
+            .map(|hop| hop.value.to_string().len())
+            .chain([self.seed.to_string().len()])
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "{start:<category_width$}  {:>value_width$}", self.seed)?;
+
+        for hop in self.hops.iter() {
+            write!(
+                f,
+                "\n{:<category_width$}  {:>value_width$}  {}",
+                hop.category,
+                hop.value,
+                hop.map()
+            )?;
+
+            match hop.applied {
+                Some(Applied { line, instruction }) => write!(f, ", line {line}: {instruction}")?,
+                None => write!(f, ": identity")?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use std::{fmt, ops::Range};
+
+use crate::mapper::{self, ConversionInstruction};
+
+/// A problem with one map of an almanac. Everything but a gap makes the almanac unusable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    ZeroLength,
+    Overflow,
+    /// The source range overlaps the one of the instruction on `line`.
+    Overlap {
+        line: usize,
+    },
+    /// Values between two source ranges that pass through unchanged.
+    Gap(Range<u128>),
+}
+
+impl Issue {
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::Gap(_))
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroLength => write!(f, "zero-length range"),
+            Self::Overflow => write!(f, "range overflows u128"),
+            Self::Overlap { line } => write!(f, "source range overlaps line {line}"),
+            Self::Gap(range) => write!(f, "no instruction covers {}..{}", range.start, range.end),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding<'a> {
+    /// The map's name as in its header, e.g. `seed-to-soil`.
+    pub map: &'a str,
+    /// 1-based line of the offending instruction, or of the header for gaps.
+    pub line: usize,
+    pub issue: Issue,
+}
+
+impl fmt::Display for Finding<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} ({} map): {}", self.line, self.map, self.issue)
+    }
+}
+
+struct MapScan<'a> {
+    name: &'a str,
+    header_line: usize,
+    lines: Vec<usize>,
+    instructions: Vec<ConversionInstruction>,
+}
+
+impl<'a> MapScan<'a> {
+    fn finish(self, findings: &mut Vec<Finding<'a>>) {
+        for (earlier, later) in mapper::overlaps(&self.instructions) {
+            findings.push(Finding {
+                map: self.name,
+                line: self.lines[later],
+                issue: Issue::Overlap {
+                    line: self.lines[earlier],
+                },
+            });
+        }
+
+        for gap in mapper::gaps(&self.instructions) {
+            findings.push(Finding {
+                map: self.name,
+                line: self.header_line,
+                issue: Issue::Gap(gap),
+            });
+        }
+    }
+}
+
+/// Checks every map of an almanac, reporting all problems rather than the first.
+///
+/// Lines that are not instructions at all are left to the parser to complain about.
+pub fn validate(input: &str) -> Vec<Finding<'_>> {
+    let mut findings = vec![];
+    let mut scan: Option<MapScan> = None;
+
+    for (idx, line) in input.lines().enumerate() {
+        if let Some(name) = line.strip_suffix(" map:") {
+            if let Some(scan) = scan.take() {
+                scan.finish(&mut findings);
+            }
+
+            scan = Some(MapScan {
+                name,
+                header_line: idx + 1,
+                lines: vec![],
+                instructions: vec![],
+            });
+
+            continue;
+        }
+
+        let (Some(scan), Ok(instruction)) =
+            (scan.as_mut(), ConversionInstruction::parse_unchecked(line))
+        else {
+            continue;
+        };
+
+        match instruction.issue() {
+            Some(issue) => findings.push(Finding {
+                map: scan.name,
+                line: idx + 1,
+                issue,
+            }),
+            None => {
+                scan.lines.push(idx + 1);
+                scan.instructions.push(instruction);
+            }
+        }
+    }
+
+    if let Some(scan) = scan {
+        scan.finish(&mut findings);
+    }
+
+    findings.sort_by_key(|finding| finding.line);
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 0
+0 99 5
+
+soil-to-fertilizer map:
+0 15 37
+39 0 10
+5 340282366920938463463374607431768211455 1";
+
+        let findings = validate(input);
+
+        assert_eq!(
+            vec![
+                "line 5 (seed-to-soil map): zero-length range",
+                "line 6 (seed-to-soil map): source range overlaps line 4",
+                "line 8 (soil-to-fertilizer map): no instruction covers 10..15",
+                "line 11 (soil-to-fertilizer map): range overflows u128",
+            ],
+            findings
+                .iter()
+                .map(|finding| finding.to_string())
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![true, true, false, true],
+            findings
+                .iter()
+                .map(|finding| finding.issue.is_error())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_validate_clean() {
+        assert!(validate("seed-to-soil map:\n50 98 2\n52 50 48").is_empty());
+    }
+}
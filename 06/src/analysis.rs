@@ -0,0 +1,73 @@
+use std::ops::RangeInclusive;
+
+use crate::{physics::Physics, Race};
+
+/// Everything worth knowing about how a race can be won.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub race: Race,
+    /// Every hold that beats the record, or `None` when none does.
+    pub window: Option<RangeInclusive<u128>>,
+    pub optimal_hold_ms: u128,
+    pub max_distance_mm: u128,
+    /// How far the optimal hold goes past the record, 0 when it does not beat it.
+    pub margin_mm: u128,
+}
+
+impl Analysis {
+    pub fn ways_to_win(&self) -> u128 {
+        self.window
+            .as_ref()
+            .map_or(0, |window| window.end() - window.start() + 1)
+    }
+}
+
+impl Race {
+    pub fn analyse(&self, physics: &impl Physics) -> Analysis {
+        let optimal_hold_ms = self.optimal_hold(physics);
+        let max_distance_mm = physics.distance(optimal_hold_ms, self.duration_ms());
+
+        Analysis {
+            race: *self,
+            window: self.winning_window(physics),
+            optimal_hold_ms,
+            max_distance_mm,
+            margin_mm: max_distance_mm.saturating_sub(self.record_distance_mm()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Boat;
+
+    use super::*;
+
+    #[test]
+    fn test_analyse() {
+        let analysis = Race::new(30, 200).analyse(&Boat::default());
+
+        assert_eq!(
+            Analysis {
+                race: Race::new(30, 200),
+                window: Some(11..=19),
+                optimal_hold_ms: 15,
+                max_distance_mm: 225,
+                margin_mm: 25,
+            },
+            analysis
+        );
+        assert_eq!(9, analysis.ways_to_win());
+    }
+
+    #[test]
+    fn test_analyse_unwinnable() {
+        let analysis = Race::new(7, 12).analyse(&Boat::default());
+
+        assert_eq!(None, analysis.window);
+        assert_eq!(3, analysis.optimal_hold_ms);
+        assert_eq!(12, analysis.max_distance_mm);
+        assert_eq!(0, analysis.margin_mm);
+        assert_eq!(0, analysis.ways_to_win());
+    }
+}
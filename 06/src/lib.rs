@@ -0,0 +1,467 @@
+mod analysis;
+mod physics;
+
+use std::ops::RangeInclusive;
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+pub use analysis::Analysis;
+pub use physics::{Boat, Physics};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    duration_ms: u128,
+    record_distance_mm: u128,
+}
+
+/// The smallest value in `low..=high` for which `predicate` holds, given that it
+/// holds for `high` and, once it does, for every larger value too.
+fn first_where(mut low: u128, mut high: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        if predicate(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    low
+}
+
+impl Race {
+    pub fn new(duration_ms: u128, record_distance_mm: u128) -> Self {
+        Self {
+            duration_ms,
+            record_distance_mm,
+        }
+    }
+
+    pub fn duration_ms(&self) -> u128 {
+        self.duration_ms
+    }
+
+    pub fn record_distance_mm(&self) -> u128 {
+        self.record_distance_mm
+    }
+
+    /// The shortest hold that goes the furthest under `physics`.
+    pub fn optimal_hold(&self, physics: &impl Physics) -> u128 {
+        physics.optimal_hold(self.duration_ms)
+    }
+
+    /// Every hold that beats the record under `physics`, if any does.
+    pub fn winning_window(&self, physics: &impl Physics) -> Option<RangeInclusive<u128>> {
+        let duration_ms = self.duration_ms;
+        let beats_record =
+            |hold_ms: u128| physics.distance(hold_ms, duration_ms) > self.record_distance_mm;
+
+        let optimal_hold = self.optimal_hold(physics);
+
+        if !beats_record(optimal_hold) {
+            return None;
+        }
+
+        // Distances rise up to the optimal hold and fall after it.
+        let first = first_where(0, optimal_hold, beats_record);
+        // Searched up to the duration itself, since one past it may not fit in u128.
+        let after = first_where(optimal_hold, duration_ms, |hold_ms| !beats_record(hold_ms));
+        let last = match beats_record(after) {
+            true => after,
+            false => after - 1,
+        };
+
+        Some(first..=last)
+    }
+
+    /// [`Race::ways_to_win`] under any `physics`.
+    pub fn ways_to_win_with(&self, physics: &impl Physics) -> u128 {
+        self.winning_window(physics)
+            .map_or(0, |window| window.end() - window.start() + 1)
+    }
+
+    fn beats_record(&self, hold_for_ms: u128) -> bool {
+        // Saturating is exact here: a distance too big for u128 beats any record.
+        hold_for_ms.saturating_mul(self.duration_ms - hold_for_ms) > self.record_distance_mm
+    }
+
+    /// The shortest hold that beats the record, given that holding for half
+    /// the race does.
+    fn shortest_winning_hold(&self) -> u128 {
+        let duration = self.duration_ms;
+
+        // Holding for `h` travels `h * (T - h)`, which exceeds the record `D`
+        // strictly between the roots `(T ± sqrt(T² - 4D)) / 2`.
+        let discriminant = duration
+            .checked_mul(duration)
+            .zip(self.record_distance_mm.checked_mul(4))
+            .map(|(square, record)| square - record);
+
+        let mut hold_for_ms = match discriminant {
+            Some(discriminant) => (duration - discriminant.isqrt()) / 2,
+            None => {
+                // Too big to square, so binary search the increasing first half instead.
+                first_where(0, duration / 2, |hold_ms| self.beats_record(hold_ms))
+            }
+        };
+
+        // The rounded root is off by at most one; a hold that only ties the
+        // record does not win.
+        while hold_for_ms > 0 && self.beats_record(hold_for_ms - 1) {
+            hold_for_ms -= 1;
+        }
+
+        while !self.beats_record(hold_for_ms) {
+            hold_for_ms += 1;
+        }
+
+        hold_for_ms
+    }
+
+    /// Ways to beat the record under the puzzle's own rules, in closed form.
+    pub fn ways_to_win(&self) -> u128 {
+        // Holding for half the race goes the furthest.
+        if !self.beats_record(self.duration_ms / 2) {
+            return 0;
+        }
+
+        // Winning holds are symmetric around the middle: `h` wins iff `T - h` does.
+        self.duration_ms - 2 * self.shortest_winning_hold() + 1
+    }
+
+    /// Tries every hold, as a reference for [`Race::ways_to_win`].
+    #[cfg(test)]
+    fn ways_to_win_by_trying(&self) -> u128 {
+        let mut ways_to_win = 0;
+
+        for hold_for_ms in 0..=self.duration_ms {
+            let travelled_distance_mm = hold_for_ms * (self.duration_ms - hold_for_ms);
+
+            if travelled_distance_mm > self.record_distance_mm {
+                ways_to_win += 1;
+            }
+        }
+
+        ways_to_win
+    }
+}
+
+/// The numbers of one line of the sheet after its `header`.
+fn parse_row<'a>(line: &'a str, header: &str) -> Result<Vec<&'a str>, ParseError> {
+    let row = line
+        .strip_prefix(header)
+        .ok_or_else(|| ParseError::new(line, format!("expected `{header}`, found")))?;
+
+    let tokens = row.split_whitespace().collect::<Vec<_>>();
+
+    for token in tokens.iter() {
+        // Kerning joins the digits, so signs are not allowed either.
+        if !token.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseError::new(token, "invalid number"));
+        }
+    }
+
+    if tokens.is_empty() {
+        return Err(ParseError::new(row, "expected at least one number"));
+    }
+
+    Ok(tokens)
+}
+
+/// The digits of every token read as a single number.
+fn kerned(tokens: &[&str]) -> Result<u128, ParseError> {
+    let mut number: u128 = 0;
+
+    for token in tokens {
+        for digit in token.bytes() {
+            number = number
+                .checked_mul(10)
+                .and_then(|number| number.checked_add(u128::from(digit - b'0')))
+                .ok_or_else(|| ParseError::new(token, "kerned number overflows u128 at"))?;
+        }
+    }
+
+    Ok(number)
+}
+
+#[derive(Debug)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+    kerned_race: Race,
+}
+
+impl TryFrom<&str> for RaceSheet {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = input.lines();
+        let time_line = lines.next().unwrap_or(input);
+        let times = parse_row(time_line, "Time:")?;
+
+        let distance_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(&input[input.len()..], "expected `Distance:`"))?;
+        let distances = parse_row(distance_line, "Distance:")?;
+
+        if let Some(line) = lines.find(|line| !line.trim().is_empty()) {
+            return Err(ParseError::new(line, "unexpected line"));
+        }
+
+        if times.len() != distances.len() {
+            let (extra, reason) = if times.len() > distances.len() {
+                (times[distances.len()], "time without a distance")
+            } else {
+                (distances[times.len()], "distance without a time")
+            };
+
+            return Err(ParseError::new(extra, reason));
+        }
+
+        let races = times
+            .iter()
+            .zip(distances.iter())
+            .map(|(time, distance)| {
+                Ok(Race::new(
+                    parse::number::<u128>(time)?,
+                    parse::number::<u128>(distance)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let kerned_race = Race::new(kerned(&times)?, kerned(&distances)?);
+
+        Ok(Self { races, kerned_race })
+    }
+}
+
+impl RaceSheet {
+    /// Each column of the sheet as its own race.
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    /// The whole sheet as one race, ignoring the spaces between numbers.
+    pub fn kerned_race(&self) -> Race {
+        self.kerned_race
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = RaceSheet;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        RaceSheet::try_from(input)
+    }
+
+    fn part_one(sheet: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(sheet).into())
+    }
+
+    fn part_two(sheet: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(sheet).into())
+    }
+}
+
+fn part_one(sheet: &RaceSheet) -> u128 {
+    sheet.races().iter().map(Race::ways_to_win).product()
+}
+
+fn part_two(sheet: &RaceSheet) -> u128 {
+    sheet.kerned_race().ways_to_win()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    static INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(4 * 8 * 9, part_one(&Day06::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(71503, part_two(&Day06::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_ways_to_win_matches_trying() {
+        for duration_ms in 0..64 {
+            // Every record up to just past the best possible distance.
+            for record_distance_mm in 0..=(duration_ms * duration_ms / 4 + 1) {
+                let race = Race {
+                    duration_ms,
+                    record_distance_mm,
+                };
+
+                assert_eq!(race.ways_to_win_by_trying(), race.ways_to_win(), "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_ways_to_win_with_default_boat() {
+        for duration_ms in 0..64 {
+            for record_distance_mm in 0..=(duration_ms * duration_ms / 4 + 1) {
+                let race = Race::new(duration_ms, record_distance_mm);
+
+                assert_eq!(
+                    race.ways_to_win(),
+                    race.ways_to_win_with(&Boat::default()),
+                    "{race:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_winning_window_matches_trying() {
+        let boats = [
+            Boat {
+                acceleration: 2,
+                max_speed: Some(5),
+                startup_delay_ms: 1,
+            },
+            Boat {
+                acceleration: 3,
+                max_speed: None,
+                startup_delay_ms: 4,
+            },
+            Boat {
+                acceleration: 1,
+                max_speed: Some(2),
+                startup_delay_ms: 0,
+            },
+            Boat {
+                acceleration: 0,
+                max_speed: None,
+                startup_delay_ms: 0,
+            },
+        ];
+
+        for boat in boats {
+            for duration_ms in 0..25 {
+                for record_distance_mm in 0..80 {
+                    let race = Race::new(duration_ms, record_distance_mm);
+                    let winning = (0..=duration_ms)
+                        .filter(|hold_ms| boat.distance(*hold_ms, duration_ms) > record_distance_mm)
+                        .collect::<Vec<_>>();
+
+                    let window = race
+                        .winning_window(&boat)
+                        .map_or(vec![], |window| window.collect::<Vec<_>>());
+
+                    assert_eq!(winning, window, "{boat:?} {race:?}");
+                    assert_eq!(winning.len() as u128, race.ways_to_win_with(&boat));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_winning_window_longest_race() {
+        let race = Race::new(u128::MAX, 5);
+
+        assert_eq!(
+            Some(1..=u128::MAX - 1),
+            race.winning_window(&Boat::default())
+        );
+
+        // Capped at 1 mm/ms, any hold leaving more than 5 ms to move wins.
+        let capped = Boat {
+            max_speed: Some(1),
+            ..Boat::default()
+        };
+
+        assert_eq!(Some(1..=u128::MAX - 6), race.winning_window(&capped));
+    }
+
+    #[test]
+    fn test_ways_to_win_exact_record() {
+        // Holding for 10 or 20 ms ties the record of 200 mm without beating it.
+        let race = Race {
+            duration_ms: 30,
+            record_distance_mm: 200,
+        };
+
+        assert!(!race.beats_record(10));
+        assert!(race.beats_record(11));
+        assert_eq!(9, race.ways_to_win());
+    }
+
+    #[test]
+    fn test_ways_to_win_huge_race() {
+        // Squaring the duration overflows, which takes the binary search path.
+        let duration_ms = u128::MAX / 2;
+        let race = Race {
+            duration_ms,
+            record_distance_mm: u128::MAX - 1,
+        };
+
+        let shortest = race.shortest_winning_hold();
+
+        assert!(race.beats_record(shortest));
+        assert!(!race.beats_record(shortest - 1));
+        assert_eq!(duration_ms - 2 * shortest + 1, race.ways_to_win());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day06::solve(input, Part::One).unwrap_err().to_string();
+
+        assert_eq!(
+            "day 06, line 1, column 1: expected `Time:`, found `7  15   30`",
+            error("7  15   30\nDistance:  9  40  200")
+        );
+        assert_eq!(
+            "day 06, line 2, column 1: expected `Distance:`, found `Record:  9  40  200`",
+            error("Time:      7  15   30\nRecord:  9  40  200")
+        );
+        assert_eq!(
+            "day 06, line 1, column 22: expected `Distance:`",
+            error("Time:      7  15   30")
+        );
+        assert_eq!(
+            "day 06, line 1, column 15: invalid number `1x`",
+            error("Time:      7  1x   30\nDistance:  9  40  200")
+        );
+        assert_eq!(
+            "day 06, line 2, column 14: invalid number `+40`",
+            error("Time:      7  15   30\nDistance:  9 +40  200")
+        );
+        assert_eq!(
+            "day 06, line 1, column 20: time without a distance `30`",
+            error("Time:      7  15   30\nDistance:  9  40")
+        );
+        assert_eq!(
+            "day 06, line 2, column 19: distance without a time `200`",
+            error("Time:      7  15\nDistance:  9  40  200")
+        );
+        assert_eq!(
+            "day 06, line 2, column 10: expected at least one number",
+            error("Time:      7\nDistance:")
+        );
+        assert_eq!(
+            "day 06, line 3, column 1: unexpected line `Time: 5`",
+            error("Time:      7\nDistance:  9\nTime: 5")
+        );
+
+        // 40 digits do not fit in a u128, even though every column does.
+        let times = ["1234567890"; 4].join(" ");
+
+        assert_eq!(
+            "day 06, line 1, column 40: kerned number overflows u128 at `1234567890`",
+            error(&format!("Time: {times}\nDistance: 1 2 3 4"))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+/// How far a boat gets in a race, depending on how long its button was held.
+///
+/// Distances must rise, or stay level, up to [`Physics::optimal_hold`] and
+/// fall, or stay level, after it. This is what lets a race find its winning
+/// holds without trying every one of them.
+pub trait Physics {
+    /// Distance travelled when holding the button for `hold_ms` of a
+    /// `duration_ms` race, saturating at `u128::MAX`.
+    fn distance(&self, hold_ms: u128, duration_ms: u128) -> u128;
+
+    /// The shortest hold that goes the furthest.
+    fn optimal_hold(&self, duration_ms: u128) -> u128;
+}
+
+/// A boat that gains `acceleration` mm/ms of speed per millisecond held, up to
+/// `max_speed`, and only starts moving `startup_delay_ms` after release.
+///
+/// The default boat follows the puzzle's rules: 1 mm/ms per millisecond held,
+/// no speed limit and no delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boat {
+    pub acceleration: u128,
+    pub max_speed: Option<u128>,
+    pub startup_delay_ms: u128,
+}
+
+impl Default for Boat {
+    fn default() -> Self {
+        Self {
+            acceleration: 1,
+            max_speed: None,
+            startup_delay_ms: 0,
+        }
+    }
+}
+
+impl Boat {
+    fn speed(&self, hold_ms: u128) -> u128 {
+        let speed = self.acceleration.saturating_mul(hold_ms);
+
+        self.max_speed
+            .map_or(speed, |max_speed| speed.min(max_speed))
+    }
+}
+
+impl Physics for Boat {
+    fn distance(&self, hold_ms: u128, duration_ms: u128) -> u128 {
+        let moving_ms = duration_ms
+            .saturating_sub(hold_ms)
+            .saturating_sub(self.startup_delay_ms);
+
+        self.speed(hold_ms).saturating_mul(moving_ms)
+    }
+
+    fn optimal_hold(&self, duration_ms: u128) -> u128 {
+        let moving_ms = duration_ms.saturating_sub(self.startup_delay_ms);
+
+        // A boat that never moves goes nowhere whatever the hold, hence 0.
+        // Without a speed limit, `a * h * (M - h)` peaks in the middle of the
+        // time left to move. Once the limit is reached holding longer only
+        // loses time, so the peak may also be right around where that happens.
+        let mut candidates = vec![0, moving_ms / 2, moving_ms.div_ceil(2)];
+
+        if let (Some(max_speed), true) = (self.max_speed, self.acceleration > 0) {
+            let limit_reached_ms = max_speed.div_ceil(self.acceleration);
+
+            candidates.extend([limit_reached_ms.saturating_sub(1), limit_reached_ms]);
+        }
+
+        candidates
+            .into_iter()
+            .map(|hold_ms| hold_ms.min(duration_ms))
+            .min_by_key(|hold_ms| {
+                (
+                    std::cmp::Reverse(self.distance(*hold_ms, duration_ms)),
+                    *hold_ms,
+                )
+            })
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_boat() {
+        let boat = Boat::default();
+
+        assert_eq!(
+            vec![0, 6, 10, 12, 12, 10, 6, 0],
+            (0..=7)
+                .map(|hold_ms| boat.distance(hold_ms, 7))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(3, boat.optimal_hold(7));
+    }
+
+    #[test]
+    fn test_boat_options() {
+        let boat = Boat {
+            acceleration: 2,
+            max_speed: Some(5),
+            startup_delay_ms: 1,
+        };
+
+        // Speeds 0, 2, 4, 5, 5, ... moving for 8, 7, 6, 5, 4, ... ms.
+        assert_eq!(
+            vec![0, 14, 24, 25, 20, 15],
+            (0..=5)
+                .map(|hold_ms| boat.distance(hold_ms, 9))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(3, boat.optimal_hold(9));
+    }
+
+    #[test]
+    fn test_optimal_hold_is_the_furthest() {
+        for acceleration in 0..4 {
+            for max_speed in [None, Some(0), Some(1), Some(3), Some(7), Some(20)] {
+                for startup_delay_ms in 0..4 {
+                    let boat = Boat {
+                        acceleration,
+                        max_speed,
+                        startup_delay_ms,
+                    };
+
+                    for duration_ms in 0..30 {
+                        let best = (0..=duration_ms)
+                            .max_by_key(|hold_ms| {
+                                (
+                                    boat.distance(*hold_ms, duration_ms),
+                                    std::cmp::Reverse(*hold_ms),
+                                )
+                            })
+                            .unwrap();
+
+                        assert_eq!(
+                            best,
+                            boat.optimal_hold(duration_ms),
+                            "{boat:?} {duration_ms}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
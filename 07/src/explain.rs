@@ -0,0 +1,300 @@
+use std::fmt;
+
+use crate::{
+    game::Game,
+    hand::{Hand, HandType},
+    rules::Rules,
+};
+
+/// What set a hand apart from a neighbour in the ranking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decider {
+    /// The hands have different types.
+    Type,
+    /// Same type; the cards first differ at this 1-based position.
+    Card(usize),
+    /// Nothing: the hands are just as strong.
+    Tie,
+}
+
+impl Decider {
+    fn between(a: &Hand, b: &Hand) -> Self {
+        if a.signature() != b.signature() {
+            return Self::Type;
+        }
+
+        a.cards()
+            .zip(b.cards())
+            .position(|(a, b)| a != b)
+            .map_or(Self::Tie, |idx| Self::Card(idx + 1))
+    }
+}
+
+impl fmt::Display for Decider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type => write!(f, "type"),
+            Self::Card(position) => write!(f, "card {position}"),
+            Self::Tie => write!(f, "tie"),
+        }
+    }
+}
+
+/// Why one hand landed where it did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    /// 1-based, the weakest hand ranking 1; tied hands may share one.
+    pub rank: usize,
+    /// The cards as dealt.
+    pub cards: String,
+    /// The cards with every wildcard replaced by the label it stands in for.
+    pub strongest_hand: String,
+    pub signature: Vec<usize>,
+    /// `None` unless the hand has five cards.
+    pub hand_type: Option<HandType>,
+    pub bid: u64,
+    /// Against the hand ranked right below, if any.
+    pub below: Option<Decider>,
+    /// Against the hand ranked right above, if any.
+    pub above: Option<Decider>,
+}
+
+impl Ranking {
+    /// Fits for every ranking of a [`Game`], which limits bids when parsing.
+    pub fn winnings(&self) -> u64 {
+        self.bid * self.rank as u64
+    }
+
+    /// The name of the hand type, or the count signature, such as `4+3`, of
+    /// hands without one.
+    pub fn type_name(&self) -> String {
+        match self.hand_type {
+            Some(hand_type) => hand_type.to_string(),
+            None => self
+                .signature
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join("+"),
+        }
+    }
+}
+
+/// Every hand of a [`Game`], weakest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub rankings: Vec<Ranking>,
+    /// The cards of every group of hands that tie, each in input order.
+    pub ties: Vec<Vec<String>>,
+}
+
+impl Explanation {
+    pub fn total_winnings(&self) -> u64 {
+        self.rankings.iter().map(Ranking::winnings).sum()
+    }
+}
+
+/// `cards` with every wildcard turned into the most common natural label,
+/// the strongest one on a tie, which is what wildcards count as when
+/// classifying a hand.
+fn strongest_hand(cards: &str, rules: &Rules) -> String {
+    let count = |label: char| cards.chars().filter(|card| *card == label).count();
+
+    // A hand of nothing but wildcards does best as the strongest label.
+    let Some(best) = rules.natural_labels().max_by_key(|label| count(*label)) else {
+        return cards.to_string();
+    };
+
+    cards
+        .chars()
+        .map(|card| if rules.is_wild(card) { best } else { card })
+        .collect()
+}
+
+impl Game {
+    pub fn explain(&self) -> Explanation {
+        let ranked = self.ranked();
+
+        let rankings = ranked
+            .iter()
+            .enumerate()
+            .map(|(idx, (rank, cards, hand, bid))| Ranking {
+                rank: *rank,
+                cards: cards.to_string(),
+                strongest_hand: strongest_hand(cards, self.rules()),
+                signature: hand.signature(),
+                hand_type: hand.hand_type(),
+                bid: *bid,
+                below: idx
+                    .checked_sub(1)
+                    .map(|below| Decider::between(hand, ranked[below].2)),
+                above: ranked
+                    .get(idx + 1)
+                    .map(|(_, _, above, _)| Decider::between(hand, above)),
+            })
+            .collect();
+
+        let ties = self
+            .ties()
+            .into_iter()
+            .map(|group| group.into_iter().map(str::to_string).collect())
+            .collect();
+
+        Explanation { rankings, ties }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decider = |decider: Option<Decider>| decider.map_or("-".to_string(), |d| d.to_string());
+
+        let header = [
+            "Rank",
+            "Cards",
+            "Strongest",
+            "Type",
+            "Bid",
+            "Winnings",
+            "Below",
+            "Above",
+        ];
+        let rows = self
+            .rankings
+            .iter()
+            .map(|ranking| {
+                [
+                    ranking.rank.to_string(),
+                    ranking.cards.clone(),
+                    ranking.strongest_hand.clone(),
+                    ranking.type_name(),
+                    ranking.bid.to_string(),
+                    ranking.winnings().to_string(),
+                    decider(ranking.below),
+                    decider(ranking.above),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let widths = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row[column].len())
+                    .chain([header[column].len()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        // Numbers are right-aligned, everything else left-aligned.
+        let numeric = [true, false, false, false, true, true, false, false];
+
+        let header = header.map(str::to_string);
+
+        for (idx, row) in [&header].into_iter().chain(rows.iter()).enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .zip(numeric)
+                .map(|((cell, width), numeric)| match numeric {
+                    true => format!("{cell:>width$}"),
+                    false => format!("{cell:<width$}"),
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            f.write_str(line.trim_end())?;
+        }
+
+        if !self.ties.is_empty() {
+            writeln!(f)?;
+        }
+
+        for group in &self.ties {
+            write!(f, "\ntied: {}", group.join(", "))?;
+        }
+
+        write!(f, "\n\ntotal winnings: {}", self.total_winnings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{game::TiePolicy, Day07};
+    use aoc2023_common::Solution;
+
+    use super::*;
+
+    static INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn test_explain() {
+        let (_, game) = Day07::parse(INPUT).unwrap();
+        let explanation = game.explain();
+
+        assert_eq!(5905, explanation.total_winnings());
+        assert_eq!(
+            "Rank  Cards  Strongest  Type            Bid  Winnings  Below   Above
+   1  32T3K  32T3K      one pair        765       765  -       type
+   2  KK677  KK677      two pair         28        56  type    type
+   3  T55J5  T5555      four of a kind  684      2052  type    card 1
+   4  QQQJA  QQQQA      four of a kind  483      1932  card 1  card 1
+   5  KTJJT  KTTTT      four of a kind  220      1100  card 1  -
+
+total winnings: 5905",
+            explanation.to_string()
+        );
+        assert!(explanation.ties.is_empty());
+    }
+
+    #[test]
+    fn test_explain_ties() {
+        let lines = [("KTJJT", "1"), ("32T3K", "10"), ("KTJJT", "100")];
+        let game = Game::parse_with(&lines, Rules::jokers(), TiePolicy::SharedRank).unwrap();
+        let explanation = game.explain();
+
+        assert_eq!(
+            vec![1, 2, 2],
+            explanation
+                .rankings
+                .iter()
+                .map(|ranking| ranking.rank)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(Decider::Tie), explanation.rankings[1].above);
+        assert_eq!(Some(Decider::Tie), explanation.rankings[2].below);
+        assert_eq!(10 + 2 + 200, explanation.total_winnings());
+        assert_eq!(vec![vec!["KTJJT", "KTJJT"]], explanation.ties);
+        assert!(explanation
+            .to_string()
+            .ends_with("\n\ntied: KTJJT, KTJJT\n\ntotal winnings: 212"));
+    }
+
+    #[test]
+    fn test_strongest_hand() {
+        let rules = Rules::jokers();
+
+        assert_eq!("KTTTT", strongest_hand("KTJJT", &rules));
+        // Ties go to the strongest label.
+        assert_eq!("22333", strongest_hand("2233J", &rules));
+        assert_eq!("AAAAA", strongest_hand("JJJJJ", &rules));
+        assert_eq!("KTJJT", strongest_hand("KTJJT", &Rules::standard()));
+
+        // The substituted hand classifies like the wildcards did.
+        for cards in ["2233J", "2345J", "JJ2J3", "T55J5"] {
+            let wild = Hand::parse(&strongest_hand(cards, &rules), &Rules::standard()).unwrap();
+
+            assert_eq!(
+                Hand::parse(cards, &rules).unwrap().signature(),
+                wild.signature()
+            );
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use aoc2023_common::{parse, ParseError};
+
+use crate::{hand::Hand, rules::Rules};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Bid(u64);
+
+impl TryFrom<&str> for Bid {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse::number::<u64>(value).map(Self)
+    }
+}
+
+/// A kind of hand that ranks against others of its kind by a sort key.
+pub trait Ranked: Sized {
+    /// What the hands are parsed and classified under.
+    type Rules;
+
+    fn parse(value: &str, rules: &Self::Rules) -> Result<Self, ParseError>;
+
+    /// Higher keys are stronger hands.
+    fn key(&self) -> u128;
+}
+
+impl Ranked for Hand {
+    type Rules = Rules;
+
+    fn parse(value: &str, rules: &Self::Rules) -> Result<Self, ParseError> {
+        Self::parse(value, rules)
+    }
+
+    fn key(&self) -> u128 {
+        self.key()
+    }
+}
+
+/// How to rank hands that are exactly as strong as each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TiePolicy {
+    /// The hand dealt first ranks lower.
+    #[default]
+    InputOrder,
+    /// Tied hands all get the lowest rank among them, and the hands above
+    /// skip the ranks they would have taken, e.g. 1, 2, 2, 4.
+    SharedRank,
+    /// Tied hands are rejected when parsing.
+    Error,
+}
+
+/// A hand as it was dealt, with its bid.
+#[derive(Debug)]
+struct Dealt<H> {
+    cards: String,
+    hand: H,
+    bid: Bid,
+}
+
+/// Every hand of the input with its bid, under one set of rules: Camel Cards
+/// [`Rules`] by default, or those of any other [`Ranked`] hand.
+#[derive(Debug)]
+pub struct Game<H: Ranked = Hand> {
+    rules: H::Rules,
+    tie_policy: TiePolicy,
+    hands: Vec<Dealt<H>>,
+}
+
+impl<H: Ranked> Game<H> {
+    /// Parses the hands, breaking ties by [`TiePolicy::InputOrder`].
+    pub fn parse(lines: &[(&str, &str)], rules: H::Rules) -> Result<Self, ParseError> {
+        Self::parse_with(lines, rules, TiePolicy::default())
+    }
+
+    pub fn parse_with(
+        lines: &[(&str, &str)],
+        rules: H::Rules,
+        tie_policy: TiePolicy,
+    ) -> Result<Self, ParseError> {
+        let hands = lines
+            .iter()
+            .map(|(cards, bid)| {
+                Ok(Dealt {
+                    cards: cards.to_string(),
+                    hand: H::parse(cards, &rules)?,
+                    bid: Bid::try_from(*bid)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        // No hand ranks above the number of hands, so winnings fit as long as
+        // that many times the bids do.
+        let mut bids = 0u64;
+
+        for (dealt, (_, bid)) in hands.iter().zip(lines) {
+            bids = bids
+                .checked_add(dealt.bid.0)
+                .filter(|bids| bids.checked_mul(hands.len() as u64).is_some())
+                .ok_or_else(|| ParseError::new(bid, "winnings overflow u64 at"))?;
+        }
+
+        let game = Self {
+            rules,
+            tie_policy,
+            hands,
+        };
+
+        if tie_policy == TiePolicy::Error {
+            // The tie whose second hand comes first in the input.
+            let tie = game.tie_groups().into_iter().min_by_key(|group| group[1]);
+
+            if let Some(group) = tie {
+                return Err(ParseError::new(
+                    lines[group[1]].0,
+                    format!("hand ties with `{}` in", lines[group[0]].0),
+                ));
+            }
+        }
+
+        Ok(game)
+    }
+
+    pub fn rules(&self) -> &H::Rules {
+        &self.rules
+    }
+
+    pub fn tie_policy(&self) -> TiePolicy {
+        self.tie_policy
+    }
+
+    /// Indices of the hands sorted weakest first, tied hands in input order.
+    fn sorted(&self) -> Vec<usize> {
+        let mut sorted = (0..self.hands.len()).collect::<Vec<_>>();
+
+        // Hands are classified up front, so this only compares integers. The
+        // sort is stable, which keeps tied hands in input order.
+        sorted.sort_by_key(|idx| self.hands[*idx].hand.key());
+
+        sorted
+    }
+
+    /// Indices of every group of hands that tie, each in input order.
+    fn tie_groups(&self) -> Vec<Vec<usize>> {
+        self.sorted()
+            .chunk_by(|a, b| self.hands[*a].hand.key() == self.hands[*b].hand.key())
+            .filter(|group| group.len() > 1)
+            .map(<[usize]>::to_vec)
+            .collect()
+    }
+
+    /// The cards of every group of hands that tie, each in input order.
+    pub fn ties(&self) -> Vec<Vec<&str>> {
+        self.tie_groups()
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|idx| self.hands[idx].cards.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Every hand's rank, cards, classification and bid, weakest first.
+    pub(crate) fn ranked(&self) -> Vec<(usize, &str, &H, u64)> {
+        let sorted = self.sorted();
+        let mut rank = 0;
+
+        sorted
+            .iter()
+            .enumerate()
+            .map(|(position, idx)| {
+                let dealt = &self.hands[*idx];
+                let tied =
+                    position > 0 && self.hands[sorted[position - 1]].hand.key() == dealt.hand.key();
+
+                if !tied || self.tie_policy != TiePolicy::SharedRank {
+                    rank = position + 1;
+                }
+
+                (rank, dealt.cards.as_str(), &dealt.hand, dealt.bid.0)
+            })
+            .collect()
+    }
+
+    /// Parsing checked that this fits.
+    pub fn total_winnings(&self) -> u64 {
+        self.ranked()
+            .iter()
+            .fold(0, |acc, (rank, _, _, bid)| acc + bid * *rank as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "KTJJT 1
+32T3K 10
+KTJJT 100
+QQQJA 1000";
+
+    fn lines() -> Vec<(&'static str, &'static str)> {
+        INPUT
+            .lines()
+            .map(|line| line.split_once(' ').unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_tie_policies() {
+        let parse = |policy| Game::<Hand>::parse_with(&lines(), Rules::jokers(), policy);
+
+        // 32T3K, QQQJA, then both KTJJT.
+        let game = parse(TiePolicy::InputOrder).unwrap();
+
+        assert_eq!(vec![vec!["KTJJT", "KTJJT"]], game.ties());
+        assert_eq!(10 + 1000 * 2 + 3 + 100 * 4, game.total_winnings());
+
+        let game = parse(TiePolicy::SharedRank).unwrap();
+
+        assert_eq!(10 + 1000 * 2 + 3 + 100 * 3, game.total_winnings());
+
+        assert_eq!(
+            "day 07, line 3, column 1: hand ties with `KTJJT` in `KTJJT`",
+            parse(TiePolicy::Error)
+                .unwrap_err()
+                .locate(7, INPUT)
+                .to_string()
+        );
+
+        let untied = Game::<Hand>::parse_with(&lines()[1..], Rules::jokers(), TiePolicy::Error);
+
+        assert!(untied.unwrap().ties().is_empty());
+    }
+
+    #[test]
+    fn test_bid_limits() {
+        let parse = |input: &str| {
+            let lines = input
+                .lines()
+                .map(|line| line.split_once(' ').unwrap())
+                .collect::<Vec<_>>();
+
+            Game::<Hand>::parse(&lines, Rules::standard())
+                .map(|game| game.total_winnings())
+                .map_err(|err| err.locate(7, input).to_string())
+        };
+
+        assert_eq!(Ok(u64::MAX), parse("KTJJT 18446744073709551615"));
+        // Twice the bids of two hands just fits.
+        assert_eq!(
+            Ok(4611686018427387903 + 4611686018427387904 * 2),
+            parse("32T3K 4611686018427387903\nKTJJT 4611686018427387904")
+        );
+        assert_eq!(
+            Err(
+                "day 07, line 2, column 7: winnings overflow u64 at `4611686018427387905`"
+                    .to_string()
+            ),
+            parse("32T3K 4611686018427387903\nKTJJT 4611686018427387905")
+        );
+    }
+}
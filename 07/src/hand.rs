@@ -0,0 +1,449 @@
+use std::fmt;
+
+use aoc2023_common::ParseError;
+
+use crate::rules::Rules;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard = 1,
+    OnePair = 2,
+    TwoPair = 3,
+    ThreeOfAKind = 4,
+    FullHouse = 5,
+    FourOfAKind = 6,
+    FiveOfAKind = 7,
+}
+
+impl HandType {
+    /// Every hand type, weakest first.
+    pub const ALL: [Self; 7] = [
+        Self::HighCard,
+        Self::OnePair,
+        Self::TwoPair,
+        Self::ThreeOfAKind,
+        Self::FullHouse,
+        Self::FourOfAKind,
+        Self::FiveOfAKind,
+    ];
+
+    /// The type of a five-card hand with this count signature, or `None` for
+    /// hands of any other size.
+    pub fn from_signature(signature: &[usize]) -> Option<Self> {
+        let hand_type = match signature {
+            // Five of a kind, where all five cards have the same label: AAAAA
+            [5] => Self::FiveOfAKind,
+            // Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+            [4, 1] => Self::FourOfAKind,
+            // Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+            [3, 2] => Self::FullHouse,
+            // Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+            [3, 1, 1] => Self::ThreeOfAKind,
+            // Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+            [2, 2, 1] => Self::TwoPair,
+            // One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+            [2, 1, 1, 1] => Self::OnePair,
+            // High card, where all cards' labels are distinct: 23456
+            [1, 1, 1, 1, 1] => Self::HighCard,
+            _ => return None,
+        };
+
+        Some(hand_type)
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::HighCard => "high card",
+            Self::OnePair => "one pair",
+            Self::TwoPair => "two pair",
+            Self::ThreeOfAKind => "three of a kind",
+            Self::FullHouse => "full house",
+            Self::FourOfAKind => "four of a kind",
+            Self::FiveOfAKind => "five of a kind",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Cards as ranks under some [`Rules`], classified once into a sort key.
+///
+/// A hand's type is its count signature: how many cards share each label,
+/// largest first, with wildcards joining the largest group. Between hands of
+/// the same size, comparing signatures from the left orders them like the
+/// named types do, e.g. `[3, 2]` (full house) beats `[3, 1, 1]` (three of a
+/// kind), so any hand size and alphabet classifies the same way.
+///
+/// The key packs the signature, padded with zeros to one count per card,
+/// above the card ranks, first card highest, so comparing keys orders hands
+/// by type first, then card by card from the left.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand {
+    key: u128,
+    len: u8,
+    card_bits: u8,
+    count_bits: u8,
+}
+
+impl Hand {
+    pub fn parse(value: &str, rules: &Rules) -> Result<Self, ParseError> {
+        for (idx, card) in value.char_indices() {
+            if rules.rank(card).is_none() {
+                return Err(ParseError::new(
+                    &value[idx..idx + card.len_utf8()],
+                    "unknown card",
+                ));
+            }
+        }
+
+        if value.chars().count() != rules.hand_size() {
+            return Err(ParseError::new(
+                value,
+                format!("expected {} cards, found", rules.hand_size()),
+            ));
+        }
+
+        Ok(Self::new(value, rules))
+    }
+
+    /// Classifies `labels`, which must all be part of the `rules`' card order
+    /// and number as many as its hand size.
+    pub(crate) fn new(labels: &str, rules: &Rules) -> Self {
+        let card_bits = rules.card_bits();
+        let count_bits = rules.count_bits();
+
+        // One count per rank, including the 0 of weakest wildcards, which
+        // stays empty since wildcards are counted apart.
+        let mut counts = vec![0; rules.labels().len() + 1];
+        let mut wildcards = 0;
+        let mut ranks = 0;
+
+        for label in labels.chars() {
+            // unwrap: Callers only pass labels of the card order.
+            let rank = rules.rank(label).unwrap();
+
+            if rules.is_wild(label) {
+                wildcards += 1;
+            } else {
+                counts[usize::from(rank)] += 1;
+            }
+
+            ranks = ranks << card_bits | u128::from(rank);
+        }
+
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.resize(rules.hand_size(), 0);
+
+        // Wildcards always do best joining the largest group, e.g. a pair
+        // becomes three of a kind rather than two pair, whichever labels
+        // they are.
+        counts[0] += wildcards;
+
+        let signature = counts
+            .iter()
+            .fold(0, |key, count| key << count_bits | *count as u128);
+
+        Self {
+            key: signature << (rules.hand_size() as u32 * card_bits) | ranks,
+            len: rules.hand_size() as u8,
+            card_bits: card_bits as u8,
+            count_bits: count_bits as u8,
+        }
+    }
+
+    /// The packed sort key: signature, then the rank of every card.
+    pub fn key(&self) -> u128 {
+        self.key
+    }
+
+    /// How many cards share each label, largest first, wildcards included.
+    pub fn signature(&self) -> Vec<usize> {
+        let signature = self.key >> (u32::from(self.len) * u32::from(self.card_bits));
+
+        (0..u32::from(self.len))
+            .rev()
+            .map(|idx| unpack(signature, idx, self.count_bits) as usize)
+            .take_while(|count| *count > 0)
+            .collect()
+    }
+
+    /// The named type of a five-card hand.
+    pub fn hand_type(&self) -> Option<HandType> {
+        HandType::from_signature(&self.signature())
+    }
+
+    /// The card ranks from left to right.
+    pub fn cards(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..u32::from(self.len))
+            .rev()
+            .map(|idx| unpack(self.key, idx, self.card_bits) as u8)
+    }
+}
+
+/// The `idx`th field of `bits` bits in `packed`, counting from the lowest.
+fn unpack(packed: u128, idx: u32, bits: u8) -> u128 {
+    (packed >> (idx * u32::from(bits))) & ((1 << bits) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::WildRank;
+
+    use super::*;
+
+    fn hand_type(hand: &str, rules: &Rules) -> HandType {
+        Hand::parse(hand, rules).unwrap().hand_type().unwrap()
+    }
+
+    #[test]
+    fn test_hand_type() {
+        let rules = Rules::standard();
+
+        assert_eq!(HandType::FiveOfAKind, hand_type("AAAAA", &rules));
+        assert_eq!(HandType::FourOfAKind, hand_type("AA8AA", &rules));
+        assert_eq!(HandType::FullHouse, hand_type("23332", &rules));
+        assert_eq!(HandType::ThreeOfAKind, hand_type("TTT98", &rules));
+        assert_eq!(HandType::TwoPair, hand_type("23432", &rules));
+        assert_eq!(HandType::OnePair, hand_type("A23A4", &rules));
+        assert_eq!(HandType::HighCard, hand_type("23456", &rules));
+        assert_eq!(HandType::TwoPair, hand_type("KTJJT", &rules));
+    }
+
+    #[test]
+    fn test_hand_type_with_jokers() {
+        let rules = Rules::jokers();
+
+        assert_eq!(HandType::OnePair, hand_type("32T3K", &rules));
+        assert_eq!(HandType::FourOfAKind, hand_type("T55J5", &rules));
+        assert_eq!(HandType::FourOfAKind, hand_type("KTJJT", &rules));
+        assert_eq!(HandType::FiveOfAKind, hand_type("JJJJJ", &rules));
+        assert_eq!(HandType::FullHouse, hand_type("2233J", &rules));
+        assert_eq!(HandType::OnePair, hand_type("2345J", &rules));
+    }
+
+    #[test]
+    fn test_key() {
+        let rules = Rules::standard();
+        let hand = Hand::parse("KTJJT", &rules).unwrap();
+
+        // Counts 2, 2, 1, 0, 0 in 3 bits each, then ranks in 4 bits each.
+        assert_eq!(0x2440_c9aa9, hand.key());
+        assert_eq!(vec![2, 2, 1], hand.signature());
+        assert_eq!(Some(HandType::TwoPair), hand.hand_type());
+        assert_eq!(vec![12, 9, 10, 10, 9], hand.cards().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_key_order_matches_type_then_cards() {
+        let rules = Rules::jokers();
+
+        // Every hand over a few labels, including the joker.
+        let labels = ['J', '2', '3', 'K'];
+        let hands = (0..4usize.pow(5))
+            .map(|mut idx| {
+                (0..5)
+                    .map(|_| {
+                        let label = labels[idx % 4];
+                        idx /= 4;
+                        label
+                    })
+                    .collect::<String>()
+            })
+            .map(|hand| Hand::parse(&hand, &rules).unwrap())
+            .collect::<Vec<_>>();
+
+        for a in hands.iter().step_by(7) {
+            for b in hands.iter() {
+                let expected = (a.hand_type(), a.cards().collect::<Vec<_>>())
+                    .cmp(&(b.hand_type(), b.cards().collect::<Vec<_>>()));
+
+                assert_eq!(expected, a.cmp(b));
+
+                let expected = (a.signature(), a.cards().collect::<Vec<_>>())
+                    .cmp(&(b.signature(), b.cards().collect::<Vec<_>>()));
+
+                assert_eq!(expected, a.cmp(b));
+            }
+        }
+    }
+
+    /// The strongest signature reachable by replacing every wildcard with
+    /// some other card, trying them all. A signature only depends on which
+    /// cards are equal, so any card not in the hand yet is as good as another one.
+    fn brute_force_signature(labels: &str, rules: &Rules) -> Vec<usize> {
+        let Some(idx) = labels.find(|label| rules.is_wild(label)) else {
+            let order = rules.labels().iter().collect::<String>();
+
+            return Hand::new(labels, &Rules::new(&order, rules.hand_size()).unwrap()).signature();
+        };
+
+        let mut naturals = rules
+            .natural_labels()
+            .filter(|label| labels.contains(*label))
+            .collect::<Vec<_>>();
+
+        naturals.extend(
+            rules
+                .natural_labels()
+                .find(|label| !labels.contains(*label)),
+        );
+
+        // A hand of nothing but wildcards, under rules where everything is wild.
+        if naturals.is_empty() {
+            return vec![rules.hand_size()];
+        }
+
+        naturals
+            .iter()
+            .map(|natural| {
+                let mut replaced = labels.to_string();
+                replaced.replace_range(idx..idx + 1, &natural.to_string());
+
+                brute_force_signature(&replaced, rules)
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_wildcards_match_brute_force() {
+        let rule_sets = [
+            Rules::jokers(),
+            Rules::standard().with_wildcards("J", WildRank::Own),
+            Rules::standard().with_wildcards("2A", WildRank::Weakest),
+            Rules::standard().with_wildcards("TJQ", WildRank::Own),
+        ];
+
+        // Every hand over a few labels, wild or not.
+        let labels = ['2', '3', 'T', 'J', 'Q', 'A'];
+
+        for rules in rule_sets.iter() {
+            for mut idx in 0..labels.len().pow(5) {
+                let hand = (0..5)
+                    .map(|_| {
+                        let label = labels[idx % labels.len()];
+                        idx /= labels.len();
+                        label
+                    })
+                    .collect::<String>();
+
+                assert_eq!(
+                    brute_force_signature(&hand, rules),
+                    Hand::parse(&hand, rules).unwrap().signature(),
+                    "{hand} {rules:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wild_rank() {
+        let own = Rules::standard().with_wildcards("J", WildRank::Own);
+        let weakest = Rules::jokers();
+        let hand = |hand, rules| Hand::parse(hand, rules).unwrap();
+
+        // Both are four of a kind, so only the tie-break on the first card differs.
+        assert!(hand("JQQQ2", &own) > hand("TQQQQ", &own));
+        assert!(hand("JQQQ2", &weakest) < hand("TQQQQ", &weakest));
+        assert!(hand("JQQQ2", &own) < hand("QQQQ2", &own));
+    }
+
+    #[test]
+    fn test_order() {
+        let rules = Rules::jokers();
+        let hand = |hand| Hand::parse(hand, &rules).unwrap();
+
+        // Same type, but J is the weakest card on its own.
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+        assert!(hand("KTJJT") > hand("QQQJA"));
+        assert!(hand("T55J5") < hand("QQQJA"));
+    }
+
+    #[test]
+    fn test_other_hand_sizes() {
+        let three = Rules::new("23456789TJQKA", 3).unwrap();
+        let seven = Rules::new("23456789TJQKA", 7)
+            .unwrap()
+            .with_wildcards("J", WildRank::Weakest);
+        let signature = |hand, rules| Hand::parse(hand, rules).unwrap().signature();
+
+        assert_eq!(vec![3], signature("QQQ", &three));
+        assert_eq!(vec![2, 1], signature("Q2Q", &three));
+        assert_eq!(None, Hand::parse("Q2Q", &three).unwrap().hand_type());
+        assert_eq!(vec![4, 3], signature("22J2KKK", &seven));
+        assert_eq!(vec![2, 2, 2, 1], signature("2233445", &seven));
+        assert_eq!(vec![7], signature("JJJJJJJ", &seven));
+
+        let hand = |hand, rules| Hand::parse(hand, rules).unwrap();
+
+        assert!(hand("22J2KKK", &seven) > hand("AAAAKQT", &seven));
+        assert!(hand("2223334", &seven) < hand("2222333", &seven));
+        assert!(hand("Q2Q", &three) > hand("AKQ", &three));
+
+        let err = Hand::parse("QQQQ", &three).unwrap_err();
+
+        assert_eq!("expected 3 cards, found", err.reason());
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        // Extra labels past the ace, with a star that is wild and weakest.
+        let rules = Rules::new("*23456789TJQKAWXYZ", 6)
+            .unwrap()
+            .with_wildcards("*", WildRank::Weakest);
+        let hand = |hand| Hand::parse(hand, &rules).unwrap();
+
+        assert_eq!(vec![4, 2], hand("ZZ*Z22").signature());
+        assert!(hand("ZZ*Z22") < hand("ZZZZ22"));
+        assert!(hand("Z23456") > hand("A23456"));
+        assert_eq!(
+            "unknown card",
+            Hand::parse("ZZ*Z2B", &rules).unwrap_err().reason()
+        );
+    }
+
+    #[test]
+    fn test_widest_alphabet() {
+        let order = (0..255)
+            .filter_map(|idx| char::from_u32(0x100 + idx))
+            .collect::<String>();
+        let rules = Rules::new(&order, 3).unwrap();
+        let hand = Hand::parse("\u{01fe}\u{0100}\u{01fe}", &rules).unwrap();
+
+        assert_eq!(vec![255, 1, 255], hand.cards().collect::<Vec<_>>());
+        assert_eq!(vec![2, 1], hand.signature());
+
+        // A weakest wildcard still ranks 0, below every label.
+        let rules = rules.with_wildcards("\u{01fe}", WildRank::Weakest);
+        let hand = Hand::parse("\u{01fe}\u{0100}\u{01fe}", &rules).unwrap();
+
+        assert_eq!(vec![0, 1, 0], hand.cards().collect::<Vec<_>>());
+        assert_eq!(vec![3], hand.signature());
+    }
+
+    #[test]
+    fn test_seven_card_wildcards_match_brute_force() {
+        let rules = Rules::new("23456789TJQKA", 7)
+            .unwrap()
+            .with_wildcards("J", WildRank::Own);
+        let labels = ['2', 'J', 'Q'];
+
+        for mut idx in 0..labels.len().pow(7) {
+            let hand = (0..7)
+                .map(|_| {
+                    let label = labels[idx % labels.len()];
+                    idx /= labels.len();
+                    label
+                })
+                .collect::<String>();
+
+            assert_eq!(
+                brute_force_signature(&hand, &rules),
+                Hand::parse(&hand, &rules).unwrap().signature(),
+                "{hand}"
+            );
+        }
+    }
+}
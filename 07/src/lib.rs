@@ -0,0 +1,100 @@
+mod explain;
+mod game;
+mod hand;
+mod odds;
+mod poker;
+mod rules;
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+pub use explain::{Decider, Explanation, Ranking};
+pub use game::{Game, Ranked, TiePolicy};
+pub use hand::{Hand, HandType};
+pub use odds::{Odds, OddsError, Sampling};
+pub use poker::{Card, Category, PokerHand, PokerRules, Suit};
+pub use rules::{Rules, RulesError, WildRank};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = (Game, Game);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines = input
+            .lines()
+            .map(|line| parse::split_once(line, " "))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((
+            Game::parse(&lines, Rules::standard())?,
+            Game::parse(&lines, Rules::jokers())?,
+        ))
+    }
+
+    fn part_one((game, _): &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(game).into())
+    }
+
+    fn part_two((_, game): &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(game).into())
+    }
+}
+
+fn part_one(game: &Game) -> u64 {
+    game.total_winnings()
+}
+
+fn part_two(game: &Game) -> u64 {
+    game.total_winnings()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    static INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn test_part1() {
+        assert_eq!(
+            765 * 1 + 220 * 2 + 28 * 3 + 684 * 4 + 483 * 5, // 6440
+            part_one(&Day07::parse(INPUT).unwrap().0)
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(5905, part_two(&Day07::parse(INPUT).unwrap().1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 07, line 2, column 4: unknown card `X`",
+            Day07::solve("32T3K 765\nT55X5 684", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 07, line 1, column 7: invalid number `76a`",
+            Day07::solve("32T3K 76a", Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 07, line 1, column 1: expected ` ` in `32T3K`",
+            Day07::solve("32T3K", Part::Two).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,461 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    hand::{Hand, HandType},
+    rules::Rules,
+};
+
+/// Above this many distinct draws, [`Odds::calculate`] samples instead.
+const EXACT_LIMIT: u128 = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OddsError {
+    UnknownCard(char),
+    TooManyCards { dealt: usize, hand_size: usize },
+    NotEnoughCards { needed: usize, remaining: usize },
+}
+
+impl fmt::Display for OddsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCard(label) => write!(f, "unknown card `{label}`"),
+            Self::TooManyCards { dealt, hand_size } => {
+                write!(f, "{dealt} cards dealt to a hand of {hand_size}")
+            }
+            Self::NotEnoughCards { needed, remaining } => {
+                write!(f, "{needed} cards to draw but {remaining} remaining")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OddsError {}
+
+/// How many random hands to complete when there are too many to enumerate,
+/// and the seed that makes the results repeatable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sampling {
+    pub samples: u64,
+    pub seed: u64,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Self {
+            samples: 100_000,
+            seed: 7,
+        }
+    }
+}
+
+/// SplitMix64, plenty random for sampling hands and tiny enough to carry here.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, with a bias far too small to matter here.
+    fn below(&mut self, n: usize) -> usize {
+        ((u128::from(self.next()) * n as u128) >> 64) as usize
+    }
+}
+
+/// Ways to pick `k` of `n` cards.
+fn choose(n: usize, k: usize) -> u128 {
+    (0..k as u128).fold(1, |ways, idx| ways * (n as u128 - idx) / (idx + 1))
+}
+
+/// A partial hand and the cards it can still draw from.
+struct Draw<'a> {
+    dealt: &'a str,
+    /// Every label of the remaining cards with how many of it remain.
+    deck: Vec<(char, usize)>,
+    needed: usize,
+    rules: &'a Rules,
+}
+
+impl<'a> Draw<'a> {
+    fn new(dealt: &'a str, deck: &str, rules: &'a Rules) -> Result<Self, OddsError> {
+        if let Some(label) = dealt
+            .chars()
+            .chain(deck.chars())
+            .find(|label| rules.rank(*label).is_none())
+        {
+            return Err(OddsError::UnknownCard(label));
+        }
+
+        let dealt_len = dealt.chars().count();
+        let needed = rules
+            .hand_size()
+            .checked_sub(dealt_len)
+            .ok_or(OddsError::TooManyCards {
+                dealt: dealt_len,
+                hand_size: rules.hand_size(),
+            })?;
+
+        let remaining = deck.chars().count();
+
+        if remaining < needed {
+            return Err(OddsError::NotEnoughCards { needed, remaining });
+        }
+
+        let mut counts: Vec<(char, usize)> = vec![];
+
+        for label in deck.chars() {
+            match counts.iter_mut().find(|(seen, _)| *seen == label) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((label, 1)),
+            }
+        }
+
+        Ok(Self {
+            dealt,
+            deck: counts,
+            needed,
+            rules,
+        })
+    }
+
+    /// How many different sets of labels could be drawn.
+    fn distinct_draws(&self) -> u128 {
+        // ways[n]: sets of n labels out of the labels seen so far.
+        let mut ways = vec![0u128; self.needed + 1];
+        ways[0] = 1;
+
+        for (_, count) in self.deck.iter() {
+            for n in (0..=self.needed).rev() {
+                ways[n] = (0..=n.min(*count)).map(|k| ways[n - k]).sum();
+            }
+        }
+
+        ways[self.needed]
+    }
+
+    fn signature(&self, drawn: &str) -> Vec<usize> {
+        Hand::new(&format!("{}{drawn}", self.dealt), self.rules).signature()
+    }
+
+    /// Every set of labels to draw, weighted by how many ways there are to
+    /// draw it, since cards with the same label are interchangeable.
+    fn enumerate(
+        &self,
+        label: usize,
+        drawn: &mut String,
+        weight: u128,
+        outcomes: &mut BTreeMap<Vec<usize>, u128>,
+    ) {
+        let needed = self.needed - drawn.chars().count();
+
+        if needed == 0 {
+            *outcomes.entry(self.signature(drawn)).or_default() += weight;
+            return;
+        }
+
+        let Some((card, count)) = self.deck.get(label) else {
+            return;
+        };
+
+        for taken in 0..=needed.min(*count) {
+            let len = drawn.len();
+            drawn.extend(std::iter::repeat_n(*card, taken));
+
+            self.enumerate(label + 1, drawn, weight * choose(*count, taken), outcomes);
+
+            drawn.truncate(len);
+        }
+    }
+}
+
+/// How likely a partial hand is to end up as each type once it draws the
+/// rest of its cards from a deck, by count signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Odds {
+    /// Ways to end up with each signature, or samples that did.
+    outcomes: BTreeMap<Vec<usize>, u128>,
+    total: u128,
+    exact: bool,
+}
+
+impl Odds {
+    /// Enumerates every way to draw the rest of the hand, when there are at
+    /// most a hundred thousand distinct ones, and samples otherwise.
+    pub fn calculate(
+        dealt: &str,
+        deck: &str,
+        rules: &Rules,
+        sampling: Sampling,
+    ) -> Result<Self, OddsError> {
+        let draw = Draw::new(dealt, deck, rules)?;
+
+        if draw.distinct_draws() <= EXACT_LIMIT {
+            Ok(Self::enumerate(&draw))
+        } else {
+            Ok(Self::sample_draw(&draw, sampling))
+        }
+    }
+
+    /// Completes the hand in every possible way from the cards of `deck`.
+    pub fn exact(dealt: &str, deck: &str, rules: &Rules) -> Result<Self, OddsError> {
+        Draw::new(dealt, deck, rules).map(|draw| Self::enumerate(&draw))
+    }
+
+    /// Completes the hand with random cards of `deck`, `sampling.samples` times.
+    pub fn sample(
+        dealt: &str,
+        deck: &str,
+        rules: &Rules,
+        sampling: Sampling,
+    ) -> Result<Self, OddsError> {
+        Draw::new(dealt, deck, rules).map(|draw| Self::sample_draw(&draw, sampling))
+    }
+
+    fn enumerate(draw: &Draw) -> Self {
+        let mut outcomes = BTreeMap::new();
+        draw.enumerate(0, &mut String::new(), 1, &mut outcomes);
+
+        Self {
+            total: outcomes.values().sum(),
+            outcomes,
+            exact: true,
+        }
+    }
+
+    fn sample_draw(draw: &Draw, sampling: Sampling) -> Self {
+        let mut rng = SplitMix64(sampling.seed);
+        let mut deck = draw
+            .deck
+            .iter()
+            .flat_map(|(label, count)| std::iter::repeat_n(*label, *count))
+            .collect::<Vec<_>>();
+        let mut outcomes = BTreeMap::new();
+
+        for _ in 0..sampling.samples {
+            // A partial shuffle, just far enough to draw the cards needed.
+            for idx in 0..draw.needed {
+                let other = idx + rng.below(deck.len() - idx);
+                deck.swap(idx, other);
+            }
+
+            let drawn = deck[..draw.needed].iter().collect::<String>();
+            *outcomes.entry(draw.signature(&drawn)).or_default() += 1;
+        }
+
+        Self {
+            outcomes,
+            total: u128::from(sampling.samples),
+            exact: false,
+        }
+    }
+
+    /// Whether every way to complete the hand was counted, or only samples.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// The number of completed hands counted.
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+
+    /// How many of the completed hands counted end up with `signature`.
+    pub fn count(&self, signature: &[usize]) -> u128 {
+        self.outcomes.get(signature).copied().unwrap_or(0)
+    }
+
+    pub fn probability(&self, signature: &[usize]) -> f64 {
+        self.count(signature) as f64 / self.total as f64
+    }
+
+    /// The probability of a five-card hand ending up as `hand_type`.
+    pub fn hand_type_probability(&self, hand_type: HandType) -> f64 {
+        self.outcomes
+            .iter()
+            .filter(|(signature, _)| HandType::from_signature(signature) == Some(hand_type))
+            .map(|(_, count)| *count as f64 / self.total as f64)
+            .sum()
+    }
+
+    /// Every signature that can come up with its probability, strongest first.
+    pub fn outcomes(&self) -> impl Iterator<Item = (&[usize], f64)> + '_ {
+        self.outcomes
+            .iter()
+            .rev()
+            .map(|(signature, count)| (signature.as_slice(), *count as f64 / self.total as f64))
+    }
+}
+
+impl fmt::Display for Odds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self
+            .outcomes()
+            .map(|(signature, probability)| {
+                let name = match HandType::from_signature(signature) {
+                    Some(hand_type) => hand_type.to_string(),
+                    None => signature
+                        .iter()
+                        .map(usize::to_string)
+                        .collect::<Vec<_>>()
+                        .join("+"),
+                };
+
+                (name, probability)
+            })
+            .collect::<Vec<_>>();
+
+        let width = names.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+        for (name, probability) in names.iter() {
+            writeln!(f, "{name:<width$}  {:>7.3}%", probability * 100.0)?;
+        }
+
+        match self.exact {
+            true => write!(f, "\nexact, over {} hands", self.total),
+            false => write!(f, "\nsampled, over {} hands", self.total),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Four of every card, like a deck of playing cards without the suits.
+    fn deck(rules: &Rules) -> String {
+        rules
+            .labels()
+            .iter()
+            .flat_map(|label| [*label; 4])
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_full_deck() {
+        let rules = Rules::standard();
+        let odds = Odds::exact("", &deck(&rules), &rules).unwrap();
+
+        // The poker counts, with straights and flushes back among the
+        // hands they would be without suits.
+        assert!(odds.is_exact());
+        assert_eq!(2_598_960, odds.total());
+        assert_eq!(0, odds.count(&[5]));
+        assert_eq!(624, odds.count(&[4, 1]));
+        assert_eq!(3_744, odds.count(&[3, 2]));
+        assert_eq!(54_912, odds.count(&[3, 1, 1]));
+        assert_eq!(123_552, odds.count(&[2, 2, 1]));
+        assert_eq!(1_098_240, odds.count(&[2, 1, 1, 1]));
+        assert_eq!(1_317_888, odds.count(&[1, 1, 1, 1, 1]));
+        assert_eq!(
+            1_317_888.0 / 2_598_960.0,
+            odds.hand_type_probability(HandType::HighCard)
+        );
+    }
+
+    #[test]
+    fn test_exact_with_jokers() {
+        let rules = Rules::jokers();
+
+        // Four jokers become five of a kind whatever comes next.
+        let odds = Odds::exact("JJJJ", "23456", &rules).unwrap();
+
+        assert_eq!(1.0, odds.hand_type_probability(HandType::FiveOfAKind));
+
+        // A pair and a joker, drawing one of five cards.
+        let odds = Odds::exact("QQJ2", "Q2345", &rules).unwrap();
+
+        assert_eq!(0.2, odds.hand_type_probability(HandType::FourOfAKind));
+        assert_eq!(0.2, odds.hand_type_probability(HandType::FullHouse));
+        assert_eq!(0.6, odds.hand_type_probability(HandType::ThreeOfAKind));
+    }
+
+    #[test]
+    fn test_sample_matches_exact() {
+        let rules = Rules::jokers();
+        let deck = deck(&rules);
+        let exact = Odds::exact("J", &deck, &rules).unwrap();
+        let sampled = Odds::sample("J", &deck, &rules, Sampling::default()).unwrap();
+
+        assert!(!sampled.is_exact());
+
+        for hand_type in HandType::ALL {
+            let difference =
+                exact.hand_type_probability(hand_type) - sampled.hand_type_probability(hand_type);
+
+            assert!(difference.abs() < 0.01, "{hand_type}");
+        }
+
+        // The same seed samples the same hands.
+        assert_eq!(
+            sampled,
+            Odds::sample("J", &deck, &rules, Sampling::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_calculate_falls_back_to_sampling() {
+        let rules = Rules::new("abcdefghijklmnopqrstuvwxyz", 13).unwrap();
+        let deck = deck(&rules);
+        let sampling = Sampling {
+            samples: 1_000,
+            seed: 1,
+        };
+
+        let odds = Odds::calculate("", &deck, &rules, sampling).unwrap();
+
+        assert!(!odds.is_exact());
+        assert_eq!(1_000, odds.total());
+        assert!(
+            (1.0 - odds
+                .outcomes()
+                .map(|(_, probability)| probability)
+                .sum::<f64>())
+            .abs()
+                < 1e-9
+        );
+
+        let rules = Rules::new("abc", 3).unwrap();
+
+        assert!(Odds::calculate("a", "abcc", &rules, sampling)
+            .unwrap()
+            .is_exact());
+    }
+
+    #[test]
+    fn test_errors() {
+        let rules = Rules::standard();
+
+        assert_eq!(
+            Err(OddsError::UnknownCard('X')),
+            Odds::exact("AX", "23", &rules)
+        );
+        assert_eq!(
+            "6 cards dealt to a hand of 5",
+            Odds::exact("AAKKQQ", "23", &rules).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "3 cards to draw but 2 remaining",
+            Odds::exact("AA", "23", &rules).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let rules = Rules::jokers();
+        let odds = Odds::exact("QQJ2", "Q2345", &rules).unwrap();
+
+        assert_eq!(
+            "four of a kind    20.000%
+full house        20.000%
+three of a kind   60.000%
+
+exact, over 5 hands",
+            odds.to_string()
+        );
+    }
+}
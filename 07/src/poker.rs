@@ -0,0 +1,421 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use aoc2023_common::ParseError;
+
+use crate::game::Ranked;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl Suit {
+    fn from_label(label: char) -> Option<Self> {
+        match label {
+            'c' => Some(Self::Clubs),
+            'd' => Some(Self::Diamonds),
+            'h' => Some(Self::Hearts),
+            's' => Some(Self::Spades),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> char {
+        match self {
+            Self::Clubs => 'c',
+            Self::Diamonds => 'd',
+            Self::Hearts => 'h',
+            Self::Spades => 's',
+        }
+    }
+}
+
+/// Rank labels from weakest to strongest; a deuce ranks 2, an ace 14.
+const RANKS: &str = "23456789TJQKA";
+
+/// A playing card such as `As`, the ace of spades.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Card {
+    rank: u8,
+    suit: Suit,
+}
+
+impl Card {
+    pub fn rank(&self) -> u8 {
+        self.rank
+    }
+
+    pub fn suit(&self) -> Suit {
+        self.suit
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut labels = value.char_indices();
+
+        let (Some((_, rank)), Some((idx, suit)), None) =
+            (labels.next(), labels.next(), labels.next())
+        else {
+            return Err(ParseError::new(
+                value,
+                "expected a rank and a suit like `As`, found",
+            ));
+        };
+
+        let rank = RANKS
+            .find(rank)
+            .ok_or_else(|| ParseError::new(&value[..idx], "unknown rank"))?;
+        let suit =
+            Suit::from_label(suit).ok_or_else(|| ParseError::new(&value[idx..], "unknown suit"))?;
+
+        Ok(Self {
+            rank: rank as u8 + 2,
+            suit,
+        })
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rank = RANKS.as_bytes()[usize::from(self.rank - 2)] as char;
+
+        write!(f, "{rank}{}", self.suit.label())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    HighCard = 1,
+    OnePair = 2,
+    TwoPair = 3,
+    ThreeOfAKind = 4,
+    Straight = 5,
+    Flush = 6,
+    FullHouse = 7,
+    FourOfAKind = 8,
+    StraightFlush = 9,
+}
+
+impl Category {
+    const ALL: [Self; 9] = [
+        Self::HighCard,
+        Self::OnePair,
+        Self::TwoPair,
+        Self::ThreeOfAKind,
+        Self::Straight,
+        Self::Flush,
+        Self::FullHouse,
+        Self::FourOfAKind,
+        Self::StraightFlush,
+    ];
+}
+
+/// How many cards a poker hand may be dealt; the best five of them play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PokerRules {
+    hand_size: usize,
+}
+
+impl PokerRules {
+    /// Five cards, all of which play.
+    pub fn five_card() -> Self {
+        Self { hand_size: 5 }
+    }
+
+    /// Seven cards, of which the best five play.
+    pub fn seven_card() -> Self {
+        Self { hand_size: 7 }
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+}
+
+/// Bits per rank in a sort key, enough for an ace's 14.
+const RANK_BITS: u32 = 4;
+
+/// The best five cards of a poker hand, classified once into a sort key.
+///
+/// The key packs the category above five tie-breaking ranks: the cards
+/// grouped by how many share a rank, larger groups first, then higher ranks
+/// first. So a full house compares its three of a kind before its pair, and
+/// a pair compares its kickers from the highest down. Suits never break ties,
+/// so hands compare and hash by their key alone.
+#[derive(Debug, Copy, Clone)]
+pub struct PokerHand {
+    key: u32,
+    cards: [Card; 5],
+}
+
+impl PokerHand {
+    pub fn parse(value: &str, rules: &PokerRules) -> Result<Self, ParseError> {
+        let mut cards = vec![];
+
+        for idx in (0..value.len()).step_by(2) {
+            let card = value
+                .get(idx..(idx + 2).min(value.len()))
+                .ok_or_else(|| ParseError::new(value, "expected cards like `AsKd`, found"))?;
+            let card = Card::try_from(card)?;
+
+            if cards.contains(&card) {
+                return Err(ParseError::new(&value[idx..idx + 2], "duplicate card"));
+            }
+
+            cards.push(card);
+        }
+
+        if cards.len() != rules.hand_size() {
+            return Err(ParseError::new(
+                value,
+                format!("expected {} cards, found", rules.hand_size()),
+            ));
+        }
+
+        Ok(Self::best_of(&cards))
+    }
+
+    /// The strongest five cards among `cards`, which must number from five
+    /// to 31.
+    pub(crate) fn best_of(cards: &[Card]) -> Self {
+        let mut best: Option<Self> = None;
+
+        // Every way to leave out all but five cards, at most 21 for seven.
+        for mut mask in 0u32..1 << cards.len() {
+            if mask.count_ones() != 5 {
+                continue;
+            }
+
+            let mut five = [cards[0]; 5];
+            let mut len = 0;
+
+            while mask != 0 {
+                five[len] = cards[mask.trailing_zeros() as usize];
+                len += 1;
+                mask &= mask - 1;
+            }
+
+            let hand = Self::new(five);
+
+            if best.is_none_or(|best| hand.key > best.key) {
+                best = Some(hand);
+            }
+        }
+
+        // unwrap: Callers always pass at least five cards.
+        best.unwrap()
+    }
+
+    fn new(mut cards: [Card; 5]) -> Self {
+        cards.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut counts = [0u8; 15];
+
+        for card in cards.iter() {
+            counts[usize::from(card.rank)] += 1;
+        }
+
+        // Ranks by group size, then rank, both descending.
+        let mut ranks = cards.map(|card| card.rank);
+        ranks.sort_unstable_by(|a, b| {
+            (counts[usize::from(*b)], b).cmp(&(counts[usize::from(*a)], a))
+        });
+
+        let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+        let distinct = counts.iter().filter(|count| **count > 0).count() == 5;
+
+        let straight = if distinct && ranks[0] - ranks[4] == 4 {
+            true
+        } else if distinct && ranks == [14, 5, 4, 3, 2] {
+            // The wheel, where the ace plays low.
+            ranks = [5, 4, 3, 2, 1];
+            true
+        } else {
+            false
+        };
+
+        let category = match (counts[usize::from(ranks[0])], counts[usize::from(ranks[3])]) {
+            _ if straight && flush => Category::StraightFlush,
+            (4, _) => Category::FourOfAKind,
+            (3, 2) => Category::FullHouse,
+            _ if flush => Category::Flush,
+            _ if straight => Category::Straight,
+            (3, _) => Category::ThreeOfAKind,
+            (2, 2) => Category::TwoPair,
+            (2, _) => Category::OnePair,
+            _ => Category::HighCard,
+        };
+
+        let key = ranks.iter().fold(category as u32, |key, rank| {
+            key << RANK_BITS | u32::from(*rank)
+        });
+
+        Self { key, cards }
+    }
+
+    /// The packed sort key: category, then the five tie-breaking ranks.
+    pub fn key(&self) -> u32 {
+        self.key
+    }
+
+    pub fn category(&self) -> Category {
+        Category::ALL[(self.key >> (5 * RANK_BITS)) as usize - 1]
+    }
+
+    /// The five cards that play, highest rank first.
+    pub fn cards(&self) -> &[Card; 5] {
+        &self.cards
+    }
+}
+
+impl PartialEq for PokerHand {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for PokerHand {}
+
+impl PartialOrd for PokerHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PokerHand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl Hash for PokerHand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl Ranked for PokerHand {
+    type Rules = PokerRules;
+
+    fn parse(value: &str, rules: &Self::Rules) -> Result<Self, ParseError> {
+        Self::parse(value, rules)
+    }
+
+    fn key(&self) -> u128 {
+        u128::from(self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::game::Game;
+
+    use super::*;
+
+    fn hand(value: &str) -> PokerHand {
+        let rules = match value.len() {
+            14 => PokerRules::seven_card(),
+            _ => PokerRules::five_card(),
+        };
+
+        PokerHand::parse(value, &rules).unwrap()
+    }
+
+    #[test]
+    fn test_category() {
+        assert_eq!(Category::StraightFlush, hand("AsKsQsJsTs").category());
+        assert_eq!(Category::FourOfAKind, hand("9c9d9h9sKd").category());
+        assert_eq!(Category::FullHouse, hand("2c2d3h3s3d").category());
+        assert_eq!(Category::Flush, hand("2h7h9hJhKh").category());
+        assert_eq!(Category::Straight, hand("6c7d8h9sTd").category());
+        assert_eq!(Category::Straight, hand("Ac2d3h4s5d").category());
+        assert_eq!(Category::ThreeOfAKind, hand("QcQdQh4s5d").category());
+        assert_eq!(Category::TwoPair, hand("QcQd4h4s5d").category());
+        assert_eq!(Category::OnePair, hand("QcQd3h4s5d").category());
+        assert_eq!(Category::HighCard, hand("QcJd3h4s5d").category());
+        assert_eq!(Category::HighCard, hand("KcAd2h3s4d").category());
+    }
+
+    #[test]
+    fn test_tie_breaks() {
+        // Kickers decide between equal pairs.
+        assert!(hand("QcQdAh4s3d") > hand("QhQsKh4c5d"));
+        // The three of a kind of a full house matters before its pair.
+        assert!(hand("3c3d3h2s2d") > hand("2c2h2dAsAd"));
+        // The wheel is the lowest straight.
+        assert!(hand("Ac2d3h4s5d") < hand("2c3d4h5s6d"));
+        // Suits never break ties.
+        assert_eq!(hand("AsKsQdJdTd"), hand("AhKhQcJcTc"));
+        assert!(hand("AsKsQdJdTd") <= hand("AhKhQcJcTc"));
+        assert!(hand("AhKhQcJcTc") <= hand("AsKsQdJdTd"));
+        assert_eq!(
+            1,
+            HashSet::from([hand("AsKsQdJdTd"), hand("AhKhQcJcTc")]).len()
+        );
+        // Camel Cards would compare position by position instead.
+        assert!(hand("2c2dAhKs3d") < hand("KcKd2h3s4d"));
+    }
+
+    #[test]
+    fn test_best_of_seven() {
+        let best = hand("AsKs2d7cQsJsTs");
+
+        assert_eq!(Category::StraightFlush, best.category());
+        assert_eq!(
+            "As Ks Qs Js Ts",
+            best.cards()
+                .iter()
+                .map(Card::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+
+        // Two pair on seven cards plays the best two pairs and kicker.
+        let best = hand("2c2d3c3d4c4dAh");
+
+        assert_eq!(Category::TwoPair, best.category());
+        assert_eq!(hand("4c4d3c3dAh").key(), best.key());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let rules = PokerRules::five_card();
+        let parse = |value| PokerHand::parse(value, &rules).unwrap_err();
+
+        assert_eq!("unknown rank", parse("AsKs1sJsTs").reason());
+        assert_eq!("1", parse("AsKs1sJsTs").text());
+        assert_eq!("unknown suit", parse("AsKsQxJsTs").reason());
+        assert_eq!("duplicate card", parse("AsKsAsJsTs").reason());
+        assert_eq!("expected 5 cards, found", parse("AsKsQsJs").reason());
+        assert_eq!(
+            "expected a rank and a suit like `As`, found",
+            parse("AsKsQsJsT").reason()
+        );
+    }
+
+    #[test]
+    fn test_total_winnings() {
+        // A straight loses to a flush here, where Camel Cards would rank it
+        // by its leading ace.
+        let lines = [
+            ("2c2dAhKs3d", "1"),
+            ("Ac2d3h4s5d", "10"),
+            ("2h7h9hJhKh", "100"),
+        ];
+        let game = Game::<PokerHand>::parse(&lines, PokerRules::five_card()).unwrap();
+
+        assert_eq!(1 + 10 * 2 + 100 * 3, game.total_winnings());
+    }
+}
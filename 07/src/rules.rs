@@ -0,0 +1,236 @@
+use std::fmt;
+
+/// Where a wildcard ranks when breaking ties between hands of the same type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WildRank {
+    /// At its own place in the card order.
+    Own,
+    /// Below every other card, like the jokers of part two.
+    Weakest,
+}
+
+/// Why a card order and hand size cannot make up [`Rules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesError {
+    NoLabels,
+    /// Ranks are a byte, with 0 reserved for weakest wildcards.
+    TooManyLabels(usize),
+    DuplicateLabel(char),
+    NoCards,
+    /// The hand's sort key would not fit in 128 bits.
+    TooLarge {
+        labels: usize,
+        hand_size: usize,
+    },
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoLabels => write!(f, "card order has no labels"),
+            Self::TooManyLabels(labels) => {
+                write!(
+                    f,
+                    "card order has {labels} labels, at most {MAX_LABELS} fit"
+                )
+            }
+            Self::DuplicateLabel(label) => write!(f, "duplicate card label `{label}`"),
+            Self::NoCards => write!(f, "hands must have at least one card"),
+            Self::TooLarge { labels, hand_size } => write!(
+                f,
+                "hands of {hand_size} cards over {labels} labels are too large to rank"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+/// The most labels a card order can have; they rank from 1 up to this.
+const MAX_LABELS: usize = u8::MAX as usize;
+
+/// Bits needed to store every value up to `max`.
+fn bits(max: usize) -> u32 {
+    usize::BITS - max.leading_zeros()
+}
+
+/// What tells two Camel Cards variants apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Card labels from weakest to strongest.
+    order: Vec<char>,
+    hand_size: usize,
+    /// Cards that stand in for whichever card makes the strongest hand type.
+    wildcards: Vec<char>,
+    wild_rank: WildRank,
+}
+
+impl Rules {
+    /// Hands of `hand_size` cards labelled by `order`, weakest first, without wildcards.
+    pub fn new(order: &str, hand_size: usize) -> Result<Self, RulesError> {
+        let order = order.chars().collect::<Vec<_>>();
+
+        if order.is_empty() {
+            return Err(RulesError::NoLabels);
+        }
+
+        if order.len() > MAX_LABELS {
+            return Err(RulesError::TooManyLabels(order.len()));
+        }
+
+        if let Some(idx) = (1..order.len()).find(|idx| order[..*idx].contains(&order[*idx])) {
+            return Err(RulesError::DuplicateLabel(order[idx]));
+        }
+
+        if hand_size == 0 {
+            return Err(RulesError::NoCards);
+        }
+
+        let rules = Self {
+            order,
+            hand_size,
+            wildcards: vec![],
+            wild_rank: WildRank::Own,
+        };
+
+        let key_bits = hand_size.checked_mul((rules.count_bits() + rules.card_bits()) as usize);
+
+        if key_bits.is_none_or(|key_bits| key_bits > u128::BITS as usize) {
+            return Err(RulesError::TooLarge {
+                labels: rules.order.len(),
+                hand_size,
+            });
+        }
+
+        Ok(rules)
+    }
+
+    /// The rules of part one, where J is a jack.
+    pub fn standard() -> Self {
+        // unwrap: Thirteen distinct labels in five cards fit comfortably.
+        Self::new("23456789TJQKA", 5).unwrap()
+    }
+
+    /// The rules of part two, where J is a joker: wild, but the weakest card on its own.
+    pub fn jokers() -> Self {
+        Self::standard().with_wildcards("J", WildRank::Weakest)
+    }
+
+    /// Makes every label of `wildcards` wild. Labels outside of the card order
+    /// never appear in a hand, so they are ignored.
+    pub fn with_wildcards(mut self, wildcards: &str, wild_rank: WildRank) -> Self {
+        self.wildcards = wildcards
+            .chars()
+            .filter(|label| self.order.contains(label))
+            .collect();
+        self.wild_rank = wild_rank;
+        self
+    }
+
+    /// Card labels from weakest to strongest.
+    pub fn labels(&self) -> &[char] {
+        &self.order
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    /// Bits per card rank in a sort key; ranks go up to the number of labels.
+    pub(crate) fn card_bits(&self) -> u32 {
+        bits(self.order.len())
+    }
+
+    /// Bits per label count in a sort key; a count goes up to the hand size.
+    pub(crate) fn count_bits(&self) -> u32 {
+        bits(self.hand_size)
+    }
+
+    pub fn is_wild(&self, label: char) -> bool {
+        self.wildcards.contains(&label)
+    }
+
+    /// The tie-breaking strength of `label`. 0 is reserved for wildcards
+    /// ranking weakest, so the weakest card in the order ranks 1.
+    pub fn rank(&self, label: char) -> Option<u8> {
+        let position = self.order.iter().position(|card| *card == label)?;
+
+        if self.wild_rank == WildRank::Weakest && self.is_wild(label) {
+            return Some(0);
+        }
+
+        Some(position as u8 + 1)
+    }
+
+    /// Every label that is not wild, from weakest to strongest.
+    pub fn natural_labels(&self) -> impl Iterator<Item = char> + '_ {
+        self.order
+            .iter()
+            .copied()
+            .filter(|label| !self.is_wild(*label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(Some(10), Rules::standard().rank('J'));
+        assert_eq!(Some(0), Rules::jokers().rank('J'));
+        assert_eq!(Some(13), Rules::jokers().rank('A'));
+        assert_eq!(None, Rules::standard().rank('X'));
+        assert!(Rules::jokers().is_wild('J'));
+        assert!(!Rules::standard().is_wild('J'));
+    }
+
+    #[test]
+    fn test_with_wildcards() {
+        let rules = Rules::standard().with_wildcards("2JX", WildRank::Own);
+
+        assert!(rules.is_wild('2'));
+        assert!(rules.is_wild('J'));
+        assert!(!rules.is_wild('X'));
+        assert_eq!(Some(1), rules.rank('2'));
+        assert_eq!(Some(10), rules.rank('J'));
+        assert_eq!("3456789TQKA", rules.natural_labels().collect::<String>());
+    }
+
+    #[test]
+    fn test_new() {
+        let rules = Rules::new("abcdefghijklmnopqrstuvwxyz", 3).unwrap();
+
+        assert_eq!(3, rules.hand_size());
+        assert_eq!(Some(26), rules.rank('z'));
+        assert_eq!(5, rules.card_bits());
+        assert_eq!(2, rules.count_bits());
+
+        assert_eq!(Err(RulesError::NoLabels), Rules::new("", 5));
+
+        let labels = |count| {
+            (0..count)
+                .filter_map(|idx| char::from_u32(0x100 + idx))
+                .collect::<String>()
+        };
+        let widest = Rules::new(&labels(255), 5).unwrap();
+
+        assert_eq!(Some(255), widest.rank('\u{01fe}'));
+        assert_eq!(8, widest.card_bits());
+        assert_eq!(
+            Err(RulesError::TooManyLabels(256)),
+            Rules::new(&labels(256), 5)
+        );
+        assert_eq!(
+            "card order has 300 labels, at most 255 fit",
+            Rules::new(&labels(300), 5).unwrap_err().to_string()
+        );
+        assert_eq!(Err(RulesError::DuplicateLabel('2')), Rules::new("2342", 5));
+        assert_eq!(Err(RulesError::NoCards), Rules::new("23", 0));
+        assert_eq!(
+            "hands of 20 cards over 13 labels are too large to rank",
+            Rules::new("23456789TJQKA", 20).unwrap_err().to_string()
+        );
+        assert!(Rules::new("23456789TJQKA", 14).is_ok());
+    }
+}
@@ -0,0 +1,494 @@
+use std::collections::HashMap;
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(ParseError::unanchored(value, "unknown instruction")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct NodeRef<'a>(&'a str);
+
+impl NodeRef<'_> {
+    fn label(&self) -> &str {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+struct Node<'a> {
+    source: NodeRef<'a>,
+    left: NodeRef<'a>,
+    right: NodeRef<'a>,
+}
+
+#[derive(Debug)]
+pub struct Network<'a> {
+    instructions: Vec<Instruction>,
+    nodes: Vec<Node<'a>>,
+}
+
+impl<'a> TryFrom<&'a str> for Node<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: &'a str) -> Result<Self, Self::Error> {
+        let (source, paths) = parse::split_once(line, " = ")?;
+
+        let paths = paths
+            .strip_prefix('(')
+            .and_then(|paths| paths.strip_suffix(')'))
+            .ok_or_else(|| ParseError::new(paths, "expected `(<left>, <right>)`, found"))?;
+
+        let (path_left, path_right) = parse::split_once(paths, ", ")?;
+
+        Ok(Node {
+            source: NodeRef(source),
+            left: NodeRef(path_left),
+            right: NodeRef(path_right),
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Network<'_>, ParseError> {
+    let line = input.lines().next().unwrap_or(input).trim();
+
+    if line.is_empty() {
+        return Err(ParseError::new(line, "expected a line of instructions"));
+    }
+
+    let instructions = line
+        .char_indices()
+        .map(|(idx, instruction)| {
+            Instruction::try_from(instruction)
+                .map_err(|err| err.at(&line[idx..idx + instruction.len_utf8()]))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let nodes = input
+        .lines()
+        .skip(2)
+        .map(Node::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for node in nodes.iter() {
+        for NodeRef(label) in [&node.left, &node.right] {
+            if !nodes.iter().any(|other| other.source.label() == *label) {
+                return Err(ParseError::new(label, "unknown node"));
+            }
+        }
+    }
+
+    Ok(Network {
+        instructions,
+        nodes,
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(network: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        part_one(network).map(Answer::from)
+    }
+
+    fn part_two(network: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        part_two(network).map(Answer::from)
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// The indices of the left and right nodes of every node.
+fn links(network: &Network) -> Vec<(usize, usize)> {
+    let index = network
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(idx, node)| (node.source.label(), idx))
+        .collect::<HashMap<_, _>>();
+
+    // Parsing checked that every left and right node exists.
+    network
+        .nodes
+        .iter()
+        .map(|node| (index[node.left.label()], index[node.right.label()]))
+        .collect()
+}
+
+/// Every step at which a walk stands on a goal node. The walk is only ever in
+/// so many places, pairs of node and position in the instructions, so after
+/// `lead` steps it loops back every `period` steps, and goals from `lead` on
+/// come around again at that period.
+#[derive(Debug, PartialEq, Eq)]
+struct Visits {
+    lead: u128,
+    period: u128,
+    goals: Vec<u128>,
+}
+
+impl Visits {
+    /// Walks from the node at `start` until it loops. `next[idx]` holds the
+    /// indices of the left and right nodes of the node at `idx`.
+    fn walk(
+        network: &Network,
+        next: &[(usize, usize)],
+        start: usize,
+        is_goal: impl Fn(&str) -> bool,
+    ) -> Self {
+        let len = network.instructions.len();
+        let mut seen = vec![None; network.nodes.len() * len];
+        let mut goals = vec![];
+        let mut idx = start;
+
+        for (step, instruction) in network.instructions.iter().cycle().enumerate() {
+            let state = idx * len + step % len;
+
+            if let Some(lead) = seen[state] {
+                return Self {
+                    lead: lead as u128,
+                    period: (step - lead) as u128,
+                    goals,
+                };
+            }
+
+            seen[state] = Some(step);
+
+            if is_goal(network.nodes[idx].source.label()) {
+                goals.push(step as u128);
+            }
+
+            idx = match instruction {
+                Instruction::Left => next[idx].0,
+                Instruction::Right => next[idx].1,
+            };
+        }
+
+        unreachable!("the instructions cycle forever")
+    }
+
+    /// The steps, modulo `period`, at which the walk stands on a goal once
+    /// it loops.
+    fn looping_goals(&self) -> impl Iterator<Item = u128> + '_ {
+        self.goals
+            .iter()
+            .filter(|step| **step >= self.lead)
+            .map(|step| step % self.period)
+    }
+
+    fn at_goal(&self, step: u128) -> bool {
+        let step = match step.checked_sub(self.lead) {
+            Some(past) => self.lead + past % self.period,
+            None => step,
+        };
+
+        self.goals.binary_search(&step).is_ok()
+    }
+}
+
+/// The step `x` with `x ≡ a (mod m)` and `x ≡ b (mod n)`, modulo the least
+/// common multiple of `m` and `n`, which is returned too. `None` when no step
+/// fits both, or the multiple overflows.
+fn crt((a, m): (u128, u128), (b, n): (u128, u128)) -> Option<(u128, u128)> {
+    let g = gcd(m, n);
+    let diff = (b + n - a % n) % n;
+
+    if diff % g != 0 {
+        return None;
+    }
+
+    let lcm = (m / g).checked_mul(n)?;
+    let (m, n) = (m / g, n / g);
+
+    // m·k ≡ diff/g (mod n), where m is now invertible modulo n.
+    let k = (diff / g % n).checked_mul(inverse(m % n, n))? % n;
+
+    Some((m.checked_mul(g)?.checked_mul(k)?.checked_add(a)? % lcm, lcm))
+}
+
+/// The inverse of `a` modulo `n`, for `a` and `n` coprime.
+fn inverse(a: u128, n: u128) -> u128 {
+    let (mut old, mut new) = (a as i128, n as i128);
+    let (mut old_s, mut new_s) = (1i128, 0i128);
+
+    while new != 0 {
+        let quotient = old / new;
+
+        (old, new) = (new, old - quotient * new);
+        (old_s, new_s) = (new_s, old_s - quotient * new_s);
+    }
+
+    old_s.rem_euclid(n as i128) as u128
+}
+
+fn part_one(network: &Network) -> Result<u128, ParseError> {
+    let Some(start) = network
+        .nodes
+        .iter()
+        .position(|node| node.source.label() == "AAA")
+    else {
+        return Err(ParseError::unanchored("AAA", "expected a node"));
+    };
+
+    let visits = Visits::walk(network, &links(network), start, |label| label == "ZZZ");
+
+    visits.goals.first().copied().ok_or_else(|| {
+        ParseError::new(
+            network.nodes[start].source.label(),
+            "never reaches `ZZZ` from",
+        )
+    })
+}
+
+/// Walking every ghost in lockstep takes far too long, so each ghost's walk is
+/// reduced to its [`Visits`]. Before every ghost loops, the ghosts all stand on
+/// a `Z` node only at a goal of the ghost that takes longest to loop; after,
+/// only at steps that fit every ghost's looping goals at once, which the
+/// Chinese remainder theorem combines.
+fn part_two(network: &Network) -> Result<u128, ParseError> {
+    let next = links(network);
+
+    let ghosts = network
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.source.label().ends_with('A'))
+        .map(|(idx, node)| {
+            let visits = Visits::walk(network, &next, idx, |label| label.ends_with('Z'));
+
+            match visits.goals.is_empty() {
+                true => Err(ParseError::new(
+                    node.source.label(),
+                    "never reaches a node ending with `Z` from",
+                )),
+                false => Ok(visits),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let Some(slowest) = ghosts.iter().max_by_key(|ghost| ghost.lead) else {
+        return Err(ParseError::unanchored(
+            "",
+            "expected a node ending with `A`",
+        ));
+    };
+
+    if let Some(step) = slowest
+        .goals
+        .iter()
+        .take_while(|step| **step < slowest.lead)
+        .find(|step| ghosts.iter().all(|ghost| ghost.at_goal(**step)))
+    {
+        return Ok(*step);
+    }
+
+    let mut steps = vec![(0, 1)];
+
+    for ghost in ghosts.iter() {
+        steps = steps
+            .iter()
+            .flat_map(|step| {
+                ghost
+                    .looping_goals()
+                    .filter_map(|goal| crt(*step, (goal, ghost.period)))
+            })
+            .collect();
+    }
+
+    steps
+        .into_iter()
+        .filter_map(|(step, period)| match step.checked_sub(slowest.lead) {
+            // The first time the step comes around once every ghost loops.
+            None => (slowest.lead - step)
+                .div_ceil(period)
+                .checked_mul(period)
+                .and_then(|skip| skip.checked_add(step)),
+            Some(_) => Some(step),
+        })
+        .min()
+        .ok_or_else(|| {
+            ParseError::unanchored("", "ghosts never stand on nodes ending with `Z` at once")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        let input2 = "LLR
+        
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        assert_eq!(Ok(2), part_one(&Day08::parse(input).unwrap()));
+        assert_eq!(Ok(6), part_one(&Day08::parse(input2).unwrap()));
+
+        assert_eq!(
+            "day 08: expected a node `AAA`",
+            Day08::solve("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        // AAA only ever turns left, away from ZZZ.
+        assert_eq!(
+            "day 08, line 3, column 1: never reaches `ZZZ` from `AAA`",
+            Day08::solve("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        assert_eq!(Ok(6), part_two(&Day08::parse(input).unwrap()));
+
+        // 22A loops between 22B and 22C without ever reaching a Z node.
+        let stuck = input.replace("22C = (22Z, 22Z)", "22C = (22B, 22B)");
+
+        assert_eq!(
+            "day 08, line 6, column 1: never reaches a node ending with `Z` from `22A`",
+            Day08::solve(&stuck, Part::Two).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_part2_unaligned() {
+        // 11Z comes around at steps 1, 3, 5, ... and 22Z at every step from 2,
+        // so the ghosts' first steps on a Z node, 1 and 2, don't line up.
+        let input = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22Z, 22Z)";
+
+        assert_eq!(Answer::from(3u128), Day08::solve(input, Part::Two).unwrap());
+
+        // Both ghosts stand on a Z node once, at step 2, before looping elsewhere.
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (XXX, XXX)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (XXX, XXX)
+XXX = (XXX, XXX)";
+
+        assert_eq!(Answer::from(2u128), Day08::solve(input, Part::Two).unwrap());
+
+        // 11Z only at odd steps and 22Z only at even ones.
+        let input = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)";
+
+        assert_eq!(
+            "day 08: ghosts never stand on nodes ending with `Z` at once",
+            Day08::solve(input, Part::Two).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((3, 4)), crt((1, 2), (3, 4)));
+        assert_eq!(Some((23, 60)), crt((3, 4), (8, 15)));
+        assert_eq!(None, crt((0, 2), (1, 4)));
+        assert_eq!(Some((0, 6)), crt((0, 1), (0, 6)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 08, line 1, column 3: unknown instruction `X`",
+            Day08::solve("LRX\n\nAAA = (BBB, CCC)", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 08, line 3, column 7: expected `(<left>, <right>)`, found `(BBB, CCC`",
+            Day08::solve("LR\n\nAAA = (BBB, CCC", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 08, line 1, column 1: expected a line of instructions",
+            Day08::solve("", Part::Two).unwrap_err().to_string()
+        );
+
+        assert_eq!(
+            "day 08, line 1, column 1: expected a line of instructions",
+            Day08::solve("\n\nAAA = (AAA, AAA)", Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 08, line 4, column 13: unknown node `CCC`",
+            Day08::solve("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, CCC)", Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use aoc2023_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct History(Vec<i64>);
+
+impl FromStr for History {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let sensor_readings = line
+            .split_whitespace()
+            .map(parse::number::<i64>)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // One reading has no differences to extrapolate from.
+        if sensor_readings.len() < 2 {
+            return Err(ParseError::new(line, "expected at least two readings"));
+        }
+
+        Ok(Self(sensor_readings))
+    }
+}
+
+impl History {
+    fn sensor_readings(&self) -> &[i64] {
+        self.0.as_slice()
+    }
+
+    fn calculate_differences(&self) -> Vec<Vec<i64>> {
+        let mut diff: Vec<i64> = self
+            .sensor_readings()
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect::<Vec<_>>();
+
+        let mut difference_history = vec![diff.clone()];
+
+        // A history that never differs down to all zeros stops at a single
+        // difference, which is then taken to stay the same.
+        while diff.len() > 1 && diff.iter().any(|x| x != &0) {
+            diff = diff
+                .windows(2)
+                .map(|window| window[1] - window[0])
+                .collect::<Vec<_>>();
+
+            difference_history.push(diff.clone());
+        }
+
+        difference_history
+    }
+
+    fn predict_next_value(&self) -> i64 {
+        let difference_history = self.calculate_differences();
+
+        let mut next_value = 0;
+
+        for prev_history in difference_history.iter().rev() {
+            let prev_value = prev_history[prev_history.len() - 1];
+
+            next_value += prev_value;
+        }
+
+        self.sensor_readings().last().unwrap() + next_value
+    }
+
+    fn predict_previous_value(&self) -> i64 {
+        let difference_history = self.calculate_differences();
+
+        let mut next_value = 0;
+
+        for prev_history in difference_history.iter().rev() {
+            let prev_value = prev_history[0];
+
+            next_value = prev_value - next_value;
+        }
+
+        self.sensor_readings().first().unwrap() - next_value
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<History>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.lines().map(History::from_str).collect()
+    }
+
+    fn part_one(histories: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_one(histories).into())
+    }
+
+    fn part_two(histories: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(part_two(histories).into())
+    }
+}
+
+fn part_one(histories: &[History]) -> i64 {
+    histories
+        .iter()
+        .map(|history| history.predict_next_value())
+        .sum()
+}
+
+fn part_two(histories: &[History]) -> i64 {
+    histories
+        .iter()
+        .map(|history| history.predict_previous_value())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    static INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(18 + 28 + 68, part_one(&Day09::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_never_all_zeros() {
+        // The differences 1 2, then 1, never reach all zeros.
+        let histories = Day09::parse("1 2 4\n3 -3").unwrap();
+
+        assert_eq!(7 - 9, part_one(&histories));
+        assert_eq!(1 + 9, part_two(&histories));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(2, part_two(&Day09::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 09, line 2, column 5: invalid number `3.5`",
+            Day09::solve("0 3 6\n1 3 3.5 10", Part::One)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 09, line 2, column 1: expected at least two readings",
+            Day09::solve("0 3 6\n\n1 3 6", Part::Two)
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "day 09, line 1, column 1: expected at least two readings `5`",
+            Day09::solve("5", Part::One).unwrap_err().to_string()
+        );
+    }
+}
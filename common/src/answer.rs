@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// Days return whatever integer type fits their puzzle best, so everything is
+/// widened into one of these variants for the tooling to compare and print.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $wide:ty, $($ty:ty),+) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value as $wide)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+impl_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(value) => value.fmt(f),
+            Self::Signed(value) => value.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_widens() {
+        assert_eq!(Answer::Unsigned(42), Answer::from(42_u32));
+        assert_eq!(Answer::Unsigned(42), Answer::from(42_usize));
+        assert_eq!(Answer::Signed(-42), Answer::from(-42_i64));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "340282366920938463463374607431768211455",
+            Answer::from(u128::MAX).to_string()
+        );
+        assert_eq!("-7", Answer::from(-7_i64).to_string());
+    }
+}
@@ -0,0 +1,202 @@
+//! The checked-in golden answers for the real puzzle inputs.
+//!
+//! Each non-empty line of the answers file reads `<day> <part> <input hash> <answer>`,
+//! where the hash is [`input_hash`] of the input the answer was recorded for, so
+//! a changed input is told apart from a changed answer. Lines starting with `#`
+//! are comments.
+
+use std::fmt;
+
+use crate::{parse, Answer, ParseError, Part};
+
+/// FNV-1a hash of an input. Unlike `DefaultHasher`, it is stable across Rust releases.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input_hash: u64,
+    pub answer: String,
+}
+
+impl TryFrom<&str> for Expected {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let mut fields = line.split_whitespace();
+        let mut next_field = || {
+            fields.next().ok_or_else(|| {
+                ParseError::new(line, "expected `<day> <part> <input hash> <answer>`, found")
+            })
+        };
+
+        let day = parse::number::<u8>(next_field()?)?;
+        let part = match next_field()? {
+            "1" => Part::One,
+            "2" => Part::Two,
+            other => return Err(ParseError::new(other, "expected part 1 or 2, found")),
+        };
+        let hash = next_field()?;
+        let input_hash = u64::from_str_radix(hash, 16)
+            .map_err(|_| ParseError::new(hash, "invalid input hash"))?;
+        let answer = next_field()?.to_string();
+
+        match fields.next() {
+            Some(extra) => Err(ParseError::new(extra, "unexpected trailing field")),
+            None => Ok(Self {
+                day,
+                part,
+                input_hash,
+                answer,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02} {} {:016x} {}",
+            self.day, self.part, self.input_hash, self.answer
+        )
+    }
+}
+
+/// Parses the contents of an answers file. Errors are located as if it were day 0.
+pub fn parse(text: &str) -> Result<Vec<Expected>, ParseError> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(Expected::try_from)
+        .collect::<Result<_, _>>()
+        .map_err(|err| err.locate(0, text))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: Answer,
+    },
+    /// The input is not the one the golden answer was recorded for.
+    InputChanged {
+        expected: u64,
+        actual: u64,
+    },
+}
+
+impl Expected {
+    /// Compares `answer`, computed on `input`, against the golden answer.
+    /// The answer is only computed when `input` is the recorded one.
+    pub fn check<E>(
+        &self,
+        input: &str,
+        answer: impl FnOnce() -> Result<Answer, E>,
+    ) -> Result<Outcome, E> {
+        let actual_hash = input_hash(input);
+
+        if actual_hash != self.input_hash {
+            return Ok(Outcome::InputChanged {
+                expected: self.input_hash,
+                actual: actual_hash,
+            });
+        }
+
+        let actual = answer()?;
+
+        if actual.to_string() == self.answer {
+            Ok(Outcome::Match)
+        } else {
+            Ok(Outcome::Mismatch {
+                expected: self.answer.clone(),
+                actual,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, input_hash(""));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, input_hash("a"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = "# day part hash answer
+05 2 00000000000000ff 46
+
+9 1 1 -3";
+
+        let expected = parse(text).unwrap();
+
+        assert_eq!(
+            vec![
+                Expected {
+                    day: 5,
+                    part: Part::Two,
+                    input_hash: 0xff,
+                    answer: "46".into(),
+                },
+                Expected {
+                    day: 9,
+                    part: Part::One,
+                    input_hash: 1,
+                    answer: "-3".into(),
+                },
+            ],
+            expected
+        );
+        assert_eq!("05 2 00000000000000ff 46", expected[0].to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 00, line 2, column 4: expected part 1 or 2, found `3`",
+            parse("01 1 ff 1\n01 3 ff 1").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "day 00, line 1, column 1: expected `<day> <part> <input hash> <answer>`, found `01 1 ff`",
+            parse("01 1 ff").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = Expected {
+            day: 6,
+            part: Part::One,
+            input_hash: input_hash("input"),
+            answer: "288".into(),
+        };
+
+        let answer = |value: u128| move || Ok::<_, ParseError>(Answer::from(value));
+
+        assert_eq!(Ok(Outcome::Match), expected.check("input", answer(288)));
+        assert_eq!(
+            Ok(Outcome::Mismatch {
+                expected: "288".into(),
+                actual: Answer::Unsigned(289),
+            }),
+            expected.check("input", answer(289))
+        );
+        assert!(matches!(
+            expected.check("other input", || -> Result<Answer, ParseError> {
+                unreachable!()
+            }),
+            Ok(Outcome::InputChanged { .. })
+        ));
+    }
+}
@@ -0,0 +1,117 @@
+//! A small std-only benchmark harness timing the parse step and both parts of a day.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        // Nearest-rank percentile, samples are never empty.
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+        Self {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+/// Benchmarks `S` on `input`, timing parsing separately from solving each part.
+pub fn run<S: Solution>(input: &str, config: &Config) -> Result<Report, ParseError> {
+    let parsed = S::parse(input).map_err(|err| err.locate(S::DAY, input))?;
+
+    for part in [S::part_one, S::part_two] {
+        part(&parsed).map_err(|err| err.locate(S::DAY, input))?;
+    }
+
+    let parse = measure(config, || S::parse(black_box(input)));
+    let part_one = measure(config, || S::part_one(black_box(&parsed)));
+    let part_two = measure(config, || S::part_two(black_box(&parsed)));
+
+    Ok(Report {
+        day: S::DAY,
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(ms(&[9, 1, 5, 3, 7, 2, 8, 4, 6, 10]));
+
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(10), stats.p95);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(ms(&[3]));
+
+        let three = Duration::from_millis(3);
+
+        assert_eq!((three, three, three), (stats.min, stats.median, stats.p95));
+    }
+}
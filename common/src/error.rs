@@ -0,0 +1,168 @@
+use std::fmt;
+
+/// A malformed or truncated puzzle input.
+///
+/// Parsers only know the offending fragment of the input, so an error starts
+/// out anchored to that fragment and is turned into a day, line and column by
+/// [`ParseError::locate`] once the whole input is known again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    day: u8,
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String,
+    // Address of the offending fragment. Only ever compared, never dereferenced.
+    anchor: Option<usize>,
+}
+
+impl ParseError {
+    /// An error about `fragment`, which must be a slice of the puzzle input for
+    /// the error to be located later on.
+    pub fn new(fragment: &str, reason: impl Into<String>) -> Self {
+        Self {
+            day: 0,
+            line: 0,
+            column: 0,
+            text: fragment.to_string(),
+            reason: reason.into(),
+            anchor: Some(fragment.as_ptr() as usize),
+        }
+    }
+
+    /// An error about a value that is not a slice of the input, such as a `char`.
+    /// Use [`ParseError::at`] to point it at the input afterwards.
+    pub fn unanchored(text: impl fmt::Display, reason: impl Into<String>) -> Self {
+        Self {
+            day: 0,
+            line: 0,
+            column: 0,
+            text: text.to_string(),
+            reason: reason.into(),
+            anchor: None,
+        }
+    }
+
+    /// Points the error at `fragment` of the input.
+    pub fn at(mut self, fragment: &str) -> Self {
+        self.text = fragment.to_string();
+        self.anchor = Some(fragment.as_ptr() as usize);
+        self
+    }
+
+    /// Resolves the line and column of the error within `input`.
+    pub fn locate(mut self, day: u8, input: &str) -> Self {
+        self.day = day;
+
+        let start = input.as_ptr() as usize;
+
+        if let Some(offset) = self
+            .anchor
+            .and_then(|anchor| anchor.checked_sub(start))
+            .filter(|offset| *offset <= input.len() && input.is_char_boundary(*offset))
+        {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+            self.line = before.matches('\n').count() + 1;
+            self.column = before[line_start..].chars().count() + 1;
+        }
+
+        self
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// 1-based line of the error, or 0 when it could not be located.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column of the error, or 0 when it could not be located.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {:02}", self.day)?;
+
+        if self.line > 0 {
+            write!(f, ", line {}, column {}", self.line, self.column)?;
+        }
+
+        write!(f, ": {}", self.reason)?;
+
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "Game 1: 3 blue
+Game 2: 4 purple";
+
+    #[test]
+    fn test_locate() {
+        let purple = &INPUT[25..31];
+        let err = ParseError::new(purple, "unknown color").locate(2, INPUT);
+
+        assert_eq!((2, 2, 11), (err.day(), err.line(), err.column()));
+        assert_eq!(
+            "day 02, line 2, column 11: unknown color `purple`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_locate_end_of_input() {
+        let err = ParseError::new(&INPUT[INPUT.len()..], "expected `;`").locate(2, INPUT);
+
+        assert_eq!((2, 17), (err.line(), err.column()));
+        assert_eq!("day 02, line 2, column 17: expected `;`", err.to_string());
+    }
+
+    #[test]
+    fn test_unanchored() {
+        let err = ParseError::unanchored('X', "unknown card").locate(7, INPUT);
+
+        assert_eq!("day 07: unknown card `X`", err.to_string());
+
+        let err = ParseError::unanchored('X', "unknown card")
+            .at(&INPUT[5..6])
+            .locate(7, INPUT);
+
+        assert_eq!(
+            "day 07, line 1, column 6: unknown card `1`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_foreign_fragment() {
+        let owned = String::from("12");
+        let err = ParseError::new(&owned, "number too large").locate(6, INPUT);
+
+        assert_eq!(0, err.line());
+        assert_eq!("day 06: number too large `12`", err.to_string());
+    }
+}
@@ -0,0 +1,291 @@
+//! A dense 2D grid for the puzzles drawn on a character map.
+
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A horizontal run of consecutive cells, such as the digits of a number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub start: Point,
+    pub len: usize,
+}
+
+impl Run {
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.start.x..self.start.x + self.len).map(|x| Point::new(x, self.start.y))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.y == self.start.y && (self.start.x..self.start.x + self.len).contains(&point.x)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line, converting each character with `cell`.
+    /// Every row must be as wide as the first one.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let mut row_width = 0;
+
+            for (idx, char) in line.char_indices() {
+                cells.push(cell(char).map_err(|err| err.at(&line[idx..idx + char.len_utf8()]))?);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        line,
+                        format!("expected a row of {width} cells, found"),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy on an empty grid.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The up to eight cells around `point`, diagonals included.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1_isize)
+            .flat_map(|dy| (-1..=1_isize).map(move |dx| (dx, dy)))
+            .filter(|delta| *delta != (0, 0))
+            .filter_map(move |(dx, dy)| self.offset(point, dx, dy))
+    }
+
+    /// The up to four cells above, below, left and right of `point`.
+    pub fn orthogonal_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(point, dx, dy))
+    }
+
+    fn offset(&self, point: Point, dx: isize, dy: isize) -> Option<Point> {
+        let x = point.x.checked_add_signed(dx)?;
+        let y = point.y.checked_add_signed(dy)?;
+
+        Some(Point::new(x, y)).filter(|point| self.contains(*point))
+    }
+
+    /// Every maximal horizontal run of cells matching `matches`, row by row.
+    pub fn runs(&self, matches: impl Fn(&T) -> bool) -> Vec<Run> {
+        let mut runs = vec![];
+
+        for (y, row) in self.rows().enumerate() {
+            let mut start = None;
+
+            for (x, cell) in row.iter().enumerate() {
+                match (start, matches(cell)) {
+                    (None, true) => start = Some(x),
+                    (Some(from), false) => {
+                        runs.push(Run {
+                            start: Point::new(from, y),
+                            len: x - from,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(from) = start {
+                runs.push(Run {
+                    start: Point::new(from, y),
+                    len: row.len() - from,
+                });
+            }
+        }
+
+        runs
+    }
+
+    /// The cells bordering `run`, diagonals included, each listed once.
+    pub fn around<'a>(&'a self, run: &'a Run) -> impl Iterator<Item = Point> + 'a {
+        let top = run.start.y.saturating_sub(1);
+        let bottom = (run.start.y + 1).min(self.height.saturating_sub(1));
+        let left = run.start.x.saturating_sub(1);
+        let right = (run.start.x + run.len).min(self.width.saturating_sub(1));
+
+        (top..=bottom)
+            .flat_map(move |y| (left..=right).map(move |x| Point::new(x, y)))
+            .filter(move |point| self.contains(*point) && !run.contains(*point))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "12.
+.*.
+..3";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(Some(&'*'), grid.get(Point::new(1, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!('3', grid[Point::new(2, 2)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "day 03, line 2, column 1: expected a row of 3 cells, found `..`",
+            Grid::parse("...\n..", Ok)
+                .unwrap_err()
+                .locate(3, "...\n..")
+                .to_string()
+        );
+
+        let input = "..\n.x";
+        let err = Grid::parse(input, |char| match char {
+            '.' => Ok(()),
+            other => Err(ParseError::unanchored(other, "unknown cell")),
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            "day 03, line 2, column 2: unknown cell `x`",
+            err.locate(3, input).to_string()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(8, grid.neighbours(Point::new(1, 1)).count());
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)],
+            grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point::new(2, 1), Point::new(1, 2)],
+            grid.orthogonal_neighbours(Point::new(2, 2))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_runs() {
+        let grid = grid();
+        let runs = grid.runs(char::is_ascii_digit);
+
+        assert_eq!(
+            vec![
+                Run {
+                    start: Point::new(0, 0),
+                    len: 2
+                },
+                Run {
+                    start: Point::new(2, 2),
+                    len: 1
+                },
+            ],
+            runs
+        );
+        assert_eq!(
+            vec![
+                Point::new(2, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1)
+            ],
+            grid.around(&runs[0]).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.around(&runs[1]).count());
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at the directory holding the puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory used when `AOC_INPUT_DIR` is not set, relative to the working directory.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `<AOC_INPUT_DIR>/2023/<day>.txt`.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for Source {
+    fn from(value: &str) -> Self {
+        match value {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { day: u8, path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { day, path } => write!(
+                f,
+                "no input for day {day:02}: {} does not exist (set {INPUT_DIR_VAR} or pass --input)",
+                path.display()
+            ),
+            Self::Unreadable { path, source } => {
+                write!(f, "cannot read {}: {source}", path.display())
+            }
+            Self::Stdin(source) => write!(f, "cannot read input from stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The input of `day` inside the input directory `dir`.
+pub fn day_path(dir: impl AsRef<Path>, day: u8) -> PathBuf {
+    dir.as_ref().join("2023").join(format!("{day:02}.txt"))
+}
+
+fn default_path_in(dir: Option<OsString>, day: u8) -> PathBuf {
+    day_path(dir.unwrap_or_else(|| DEFAULT_INPUT_DIR.into()), day)
+}
+
+/// The conventional location of a day's input, honouring `AOC_INPUT_DIR`.
+pub fn default_path(day: u8) -> PathBuf {
+    default_path_in(env::var_os(INPUT_DIR_VAR), day)
+}
+
+fn read_file(day: u8, path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing {
+            day,
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+/// Reads the puzzle input of `day` from `source`.
+pub fn load(day: u8, source: &Source) -> Result<String, InputError> {
+    match source {
+        Source::Default => read_file(day, &default_path(day)),
+        Source::Path(path) => read_file(day, path),
+        Source::Stdin => {
+            let mut input = String::new();
+
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+
+            Ok(input)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(Path::new("inputs/2023/05.txt"), default_path_in(None, 5));
+        assert_eq!(
+            Path::new("/tmp/aoc/2023/12.txt"),
+            default_path_in(Some("/tmp/aoc".into()), 12)
+        );
+    }
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::Stdin, Source::from("-"));
+        assert_eq!(Source::Path("05.txt".into()), Source::from("05.txt"));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = load(5, &Source::Path("does/not/exist.txt".into())).unwrap_err();
+
+        assert!(matches!(err, InputError::Missing { day: 5, .. }));
+        assert_eq!(
+            "no input for day 05: does/not/exist.txt does not exist (set AOC_INPUT_DIR or pass --input)",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+mod answer;
+pub mod answers;
+pub mod bench;
+mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use solution::{Part, Solution};
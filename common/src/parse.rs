@@ -0,0 +1,41 @@
+//! Small helpers for the parsers of each day, reporting failures as [`ParseError`]s.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Parses `token` as a number, complaining about the token itself otherwise.
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(token, "invalid number"))
+}
+
+/// [`str::split_once`] that complains about `text` when `separator` is missing.
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(text, format!("expected `{}` in", separator.escape_debug())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(Ok(42), number::<u32>("42"));
+        assert_eq!(
+            "day 00: invalid number `4x`",
+            number::<u32>("4x").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        assert_eq!(Ok(("Game 1", " 3 blue")), split_once("Game 1: 3 blue", ":"));
+        assert_eq!(
+            "day 00: expected `:` in `Game 1`",
+            split_once("Game 1", ":").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use crate::{Answer, ParseError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A solved day of the calendar.
+///
+/// The puzzle input is parsed once into `Input`, which both parts then share.
+/// A part may still reject an input that parsed, when it lacks what only that
+/// part needs. Tooling such as the runner only ever talks to days through this
+/// trait.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, ParseError>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, ParseError>;
+
+    fn solve(input: &str, part: Part) -> Result<Answer, ParseError> {
+        let parsed = Self::parse(input).map_err(|err| err.locate(Self::DAY, input))?;
+
+        match part {
+            Part::One => Self::part_one(&parsed),
+            Part::Two => Self::part_two(&parsed),
+        }
+        .map_err(|err| err.locate(Self::DAY, input))
+    }
+}
@@ -0,0 +1,427 @@
+use std::path::PathBuf;
+
+use aoc2023_07::TiePolicy;
+use aoc2023_common::{bench, input::Source, Part};
+
+pub static USAGE: &str = "Usage:
+    aoc run <day> [--part <1|2>] [--input <path|->]
+    aoc run --all [--part <1|2>]
+    aoc bench <day|--all> [--input <path|->] [--warmup <n>] [--iterations <n>] [--json <path|->]
+    aoc verify [<day>|--all] [--part <1|2>] [--answers <path>] [--record]
+    aoc trace <seed> [--input <path|->] [--json]
+    aoc races [--input <path|->]
+    aoc explain [--part <1|2>] [--ties <input-order|shared|error>] [--input <path|->] [--json]
+    aoc help
+
+Inputs are read from $AOC_INPUT_DIR/2023/<day>.txt (default: inputs/2023/<day>.txt)
+unless --input is given; pass `--input -` to read from stdin.
+
+Benchmark results are printed as a table; --json also writes them as JSON to
+<path>, or to stdout instead of the table when <path> is `-`.
+
+verify checks the answers for the real inputs against the golden answers in
+answers.txt (or --answers). With --record, parts without a golden answer, or
+whose input changed, get their current answer recorded.
+
+trace follows a seed through the maps of the day 05 almanac, showing the value
+in every category and the instruction that produced it.
+
+races analyses every race of the day 06 sheet, and the kerned race of part two:
+the holds that beat the record, the best hold, its distance and the margin.
+
+explain ranks the day 07 hands under the rules of a part (default: 2), showing
+for every hand its cards with jokers substituted, its type, rank and winnings,
+and what set it apart from the hands ranked right below and above it. Tied
+hands are listed too; --ties ranks them in input order (default), shares a
+rank among them, or rejects the input.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Target {
+    Day(u8),
+    All,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        target: Target,
+        part: Option<Part>,
+        input: Option<Source>,
+    },
+    Bench {
+        target: Target,
+        input: Option<Source>,
+        config: bench::Config,
+        json: Option<PathBuf>,
+    },
+    Verify {
+        target: Target,
+        part: Option<Part>,
+        answers: PathBuf,
+        record: bool,
+    },
+    Trace {
+        seed: u128,
+        input: Option<Source>,
+        json: bool,
+    },
+    Races {
+        input: Option<Source>,
+    },
+    Explain {
+        part: Part,
+        ties: TiePolicy,
+        input: Option<Source>,
+        json: bool,
+    },
+    Help,
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part `{value}`, expected 1 or 2")),
+    }
+}
+
+fn parse_count(flag: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("invalid value `{value}` for {flag}"))
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("missing value for {flag}"))
+}
+
+fn parse_day(target: &mut Option<Target>, day: &str) -> Result<(), String> {
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| format!("invalid day `{day}`"))?;
+
+    *target = Some(Target::Day(day));
+
+    Ok(())
+}
+
+fn check_target(target: Option<Target>, input: &Option<Source>) -> Result<Target, String> {
+    let target = target.ok_or("expected a day or --all")?;
+
+    if target == Target::All && input.is_some() {
+        return Err("--input cannot be combined with --all".into());
+    }
+
+    Ok(target)
+}
+
+fn parse_run(args: &mut impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut target = None;
+    let mut part = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => target = Some(Target::All),
+            "--part" => part = Some(parse_part(&value(args, "--part")?)?),
+            "--input" => input = Some(Source::from(value(args, "--input")?.as_str())),
+            day if target.is_none() && !day.starts_with('-') => parse_day(&mut target, day)?,
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    Ok(Command::Run {
+        target: check_target(target, &input)?,
+        part,
+        input,
+    })
+}
+
+fn parse_bench(args: &mut impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut target = None;
+    let mut input = None;
+    let mut config = bench::Config::default();
+    let mut json = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => target = Some(Target::All),
+            "--input" => input = Some(Source::from(value(args, "--input")?.as_str())),
+            "--warmup" => config.warmup = parse_count("--warmup", &value(args, "--warmup")?)?,
+            "--iterations" => {
+                config.iterations = parse_count("--iterations", &value(args, "--iterations")?)?;
+
+                if config.iterations == 0 {
+                    return Err("--iterations must be at least 1".into());
+                }
+            }
+            "--json" => json = Some(PathBuf::from(value(args, "--json")?)),
+            day if target.is_none() && !day.starts_with('-') => parse_day(&mut target, day)?,
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    Ok(Command::Bench {
+        target: check_target(target, &input)?,
+        input,
+        config,
+        json,
+    })
+}
+
+fn parse_verify(args: &mut impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut target = None;
+    let mut part = None;
+    let mut answers = PathBuf::from("answers.txt");
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => target = Some(Target::All),
+            "--part" => part = Some(parse_part(&value(args, "--part")?)?),
+            "--answers" => answers = PathBuf::from(value(args, "--answers")?),
+            "--record" => record = true,
+            day if target.is_none() && !day.starts_with('-') => parse_day(&mut target, day)?,
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    Ok(Command::Verify {
+        target: target.unwrap_or(Target::All),
+        part,
+        answers,
+        record,
+    })
+}
+
+fn parse_trace(args: &mut impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut seed = None;
+    let mut input = None;
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(Source::from(value(args, "--input")?.as_str())),
+            "--json" => json = true,
+            number if seed.is_none() && !number.starts_with('-') => {
+                seed = Some(
+                    number
+                        .parse::<u128>()
+                        .map_err(|_| format!("invalid seed `{number}`"))?,
+                );
+            }
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    Ok(Command::Trace {
+        seed: seed.ok_or("expected a seed")?,
+        input,
+        json,
+    })
+}
+
+fn parse_races(args: &mut impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(Source::from(value(args, "--input")?.as_str())),
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    Ok(Command::Races { input })
+}
+
+fn parse_ties(value: &str) -> Result<TiePolicy, String> {
+    match value {
+        "input-order" => Ok(TiePolicy::InputOrder),
+        "shared" => Ok(TiePolicy::SharedRank),
+        "error" => Ok(TiePolicy::Error),
+        _ => Err(format!(
+            "invalid tie policy `{value}`, expected input-order, shared or error"
+        )),
+    }
+}
+
+fn parse_explain(args: &mut impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut part = Part::Two;
+    let mut ties = TiePolicy::default();
+    let mut input = None;
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = parse_part(&value(args, "--part")?)?,
+            "--ties" => ties = parse_ties(&value(args, "--ties")?)?,
+            "--input" => input = Some(Source::from(value(args, "--input")?.as_str())),
+            "--json" => json = true,
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    Ok(Command::Explain {
+        part,
+        ties,
+        input,
+        json,
+    })
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(&mut args),
+        Some("bench") => parse_bench(&mut args),
+        Some("verify") => parse_verify(&mut args),
+        Some("trace") => parse_trace(&mut args),
+        Some("races") => parse_races(&mut args),
+        Some("explain") => parse_explain(&mut args),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command `{other}`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_run_day() {
+        assert_eq!(
+            Ok(Command::Run {
+                target: Target::Day(5),
+                part: Some(Part::Two),
+                input: Some(Source::Path("path".into())),
+            }),
+            parse(args("run 5 --part 2 --input path"))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            Ok(Command::Run {
+                target: Target::All,
+                part: None,
+                input: None,
+            }),
+            parse(args("run --all"))
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            Ok(Command::Bench {
+                target: Target::All,
+                input: None,
+                config: bench::Config {
+                    warmup: 1,
+                    iterations: 50,
+                },
+                json: Some("-".into()),
+            }),
+            parse(args("bench --all --warmup 1 --iterations 50 --json -"))
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            Ok(Command::Verify {
+                target: Target::All,
+                part: None,
+                answers: "answers.txt".into(),
+                record: false,
+            }),
+            parse(args("verify"))
+        );
+        assert_eq!(
+            Ok(Command::Verify {
+                target: Target::Day(3),
+                part: Some(Part::One),
+                answers: "other.txt".into(),
+                record: true,
+            }),
+            parse(args("verify 3 --part 1 --answers other.txt --record"))
+        );
+    }
+
+    #[test]
+    fn test_parse_trace() {
+        assert_eq!(
+            Ok(Command::Trace {
+                seed: 79,
+                input: Some(Source::Stdin),
+                json: true,
+            }),
+            parse(args("trace 79 --input - --json"))
+        );
+    }
+
+    #[test]
+    fn test_parse_races() {
+        assert_eq!(Ok(Command::Races { input: None }), parse(args("races")));
+        assert_eq!(
+            Ok(Command::Races {
+                input: Some(Source::Path("sheet.txt".into())),
+            }),
+            parse(args("races --input sheet.txt"))
+        );
+    }
+
+    #[test]
+    fn test_parse_explain() {
+        assert_eq!(
+            Ok(Command::Explain {
+                part: Part::Two,
+                ties: TiePolicy::InputOrder,
+                input: None,
+                json: false,
+            }),
+            parse(args("explain"))
+        );
+        assert_eq!(
+            Ok(Command::Explain {
+                part: Part::One,
+                ties: TiePolicy::SharedRank,
+                input: Some(Source::Stdin),
+                json: true,
+            }),
+            parse(args("explain --part 1 --ties shared --input - --json"))
+        );
+        assert_eq!(
+            Ok(Command::Explain {
+                part: Part::Two,
+                ties: TiePolicy::Error,
+                input: None,
+                json: false,
+            }),
+            parse(args("explain --ties error"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(args("run")).is_err());
+        assert!(parse(args("run 5 --part 3")).is_err());
+        assert!(parse(args("run --all --input path")).is_err());
+        assert!(parse(args("bench 5 --iterations 0")).is_err());
+        assert!(parse(args("bench 5 --part 1")).is_err());
+        assert!(parse(args("trace")).is_err());
+        assert!(parse(args("trace seed")).is_err());
+        assert!(parse(args("races 6")).is_err());
+        assert!(parse(args("explain --part 3")).is_err());
+        assert!(parse(args("explain 7")).is_err());
+        assert!(parse(args("explain --ties first")).is_err());
+        assert!(parse(args("fly 5")).is_err());
+    }
+}
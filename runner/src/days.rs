@@ -0,0 +1,188 @@
+use crate::cli::Target;
+
+use aoc2023_common::{bench, Answer, ParseError, Part, Solution};
+
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, Part) -> Result<Answer, ParseError>,
+    bench: fn(&str, &bench::Config) -> Result<bench::Report, ParseError>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: S::solve,
+            bench: bench::run::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        (self.solve)(input, part)
+    }
+
+    pub fn bench(&self, input: &str, config: &bench::Config) -> Result<bench::Report, ParseError> {
+        (self.bench)(input, config)
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::of::<aoc2023_01::Day01>(),
+    Day::of::<aoc2023_02::Day02>(),
+    Day::of::<aoc2023_03::Day03>(),
+    Day::of::<aoc2023_04::Day04>(),
+    Day::of::<aoc2023_05::Day05>(),
+    Day::of::<aoc2023_06::Day06>(),
+    Day::of::<aoc2023_07::Day07>(),
+    Day::of::<aoc2023_08::Day08>(),
+    Day::of::<aoc2023_09::Day09>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub fn select(target: &Target) -> Result<Vec<&'static Day>, String> {
+    match target {
+        Target::All => Ok(DAYS.iter().collect()),
+        Target::Day(number) => find(*number)
+            .map(|day| vec![day])
+            .ok_or_else(|| format!("day {number} is not solved yet")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    static SCHEMATIC: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    static CARDS: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    static ALMANAC: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    static RACES: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    static HANDS: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    static HISTORIES: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    /// The puzzle's example for every day and part, with its answer.
+    static EXAMPLES: &[(u8, Part, &str, &str)] = &[
+        (
+            1,
+            Part::One,
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet",
+            "142",
+        ),
+        (
+            1,
+            Part::Two,
+            "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen",
+            "281",
+        ),
+        (2, Part::One, GAMES, "8"),
+        (2, Part::Two, GAMES, "2286"),
+        (3, Part::One, SCHEMATIC, "4361"),
+        (3, Part::Two, SCHEMATIC, "467835"),
+        (4, Part::One, CARDS, "13"),
+        (4, Part::Two, CARDS, "30"),
+        (5, Part::One, ALMANAC, "35"),
+        (5, Part::Two, ALMANAC, "46"),
+        (6, Part::One, RACES, "288"),
+        (6, Part::Two, RACES, "71503"),
+        (7, Part::One, HANDS, "6440"),
+        (7, Part::Two, HANDS, "5905"),
+        (
+            8,
+            Part::One,
+            "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)",
+            "6",
+        ),
+        (
+            8,
+            Part::Two,
+            "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)",
+            "6",
+        ),
+        (9, Part::One, HISTORIES, "114"),
+        (9, Part::Two, HISTORIES, "2"),
+    ];
+
+    #[test]
+    fn test_every_day_solves_its_examples() {
+        for day in DAYS {
+            for part in Part::ALL {
+                let (_, _, input, expected) = EXAMPLES
+                    .iter()
+                    .find(|(number, example_part, _, _)| {
+                        *number == day.number && *example_part == part
+                    })
+                    .unwrap_or_else(|| panic!("no example for day {} {part:?}", day.number));
+
+                let answer = day.solve(part, input).unwrap();
+
+                assert_eq!(*expected, answer.to_string(), "day {} {part:?}", day.number);
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt::Write;
+
+use aoc2023_05::Trace;
+use aoc2023_07::{Decider, Explanation};
+use aoc2023_common::bench;
+
+/// Writes `value` as a JSON string literal.
+fn string(json: &mut String, value: &str) {
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+}
+
+fn stats(json: &mut String, name: &str, stats: &bench::Stats) {
+    write!(
+        json,
+        r#""{name}":{{"min_ns":{},"median_ns":{},"p95_ns":{}}}"#,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.p95.as_nanos()
+    )
+    .unwrap();
+}
+
+/// Benchmark results as a single JSON object, durations in nanoseconds.
+pub fn render_bench(config: &bench::Config, reports: &[bench::Report]) -> String {
+    let mut json = String::new();
+
+    // unwrap: Writing into a String never fails.
+    write!(
+        json,
+        r#"{{"warmup":{},"iterations":{},"days":["#,
+        config.warmup, config.iterations
+    )
+    .unwrap();
+
+    for (idx, report) in reports.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+
+        write!(json, r#"{{"day":{},"#, report.day).unwrap();
+        stats(&mut json, "parse", &report.parse);
+        json.push(',');
+        stats(&mut json, "part_one", &report.part_one);
+        json.push(',');
+        stats(&mut json, "part_two", &report.part_two);
+        json.push('}');
+    }
+
+    json.push_str("]}");
+
+    json
+}
+
+/// A Day 05 seed trace as a single JSON object. Hops that passed through
+/// unchanged have a `null` line and instruction.
+pub fn render_trace(trace: &Trace) -> String {
+    let mut json = String::new();
+
+    write!(
+        json,
+        r#"{{"seed":{},"location":{},"hops":["#,
+        trace.seed,
+        trace.result()
+    )
+    .unwrap();
+
+    for (idx, hop) in trace.hops.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+
+        json.push_str(r#"{"map":"#);
+        string(&mut json, &hop.map());
+        json.push_str(r#","category":"#);
+        string(&mut json, hop.category);
+        write!(json, r#","value":{},"#, hop.value).unwrap();
+
+        match hop.applied {
+            Some(applied) => write!(
+                json,
+                r#""line":{},"instruction":"{}"}}"#,
+                applied.line, applied.instruction
+            )
+            .unwrap(),
+            None => json.push_str(r#""line":null,"instruction":null}"#),
+        }
+    }
+
+    json.push_str("]}");
+
+    json
+}
+
+fn decider(json: &mut String, decider: Option<Decider>) {
+    match decider {
+        Some(Decider::Type) => json.push_str(r#"{"by":"type"}"#),
+        Some(Decider::Card(position)) => {
+            write!(json, r#"{{"by":"card","position":{position}}}"#).unwrap()
+        }
+        Some(Decider::Tie) => json.push_str(r#"{"by":"tie"}"#),
+        None => json.push_str("null"),
+    }
+}
+
+/// A Day 07 explanation as a single JSON object, hands weakest first. What
+/// set a hand apart from its neighbours is `null` at either end; `ties` lists
+/// the cards of every group of tied hands.
+pub fn render_explanation(explanation: &Explanation) -> String {
+    let mut json = String::new();
+
+    write!(
+        json,
+        r#"{{"total_winnings":{},"hands":["#,
+        explanation.total_winnings()
+    )
+    .unwrap();
+
+    for (idx, ranking) in explanation.rankings.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+
+        write!(json, r#"{{"rank":{},"cards":"#, ranking.rank).unwrap();
+        string(&mut json, &ranking.cards);
+        json.push_str(r#","strongest_hand":"#);
+        string(&mut json, &ranking.strongest_hand);
+        json.push_str(r#","type":"#);
+        string(&mut json, &ranking.type_name());
+        write!(
+            json,
+            r#","signature":[{}],"bid":{},"winnings":{},"below":"#,
+            ranking
+                .signature
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+            ranking.bid,
+            ranking.winnings()
+        )
+        .unwrap();
+        decider(&mut json, ranking.below);
+        json.push_str(r#","above":"#);
+        decider(&mut json, ranking.above);
+        json.push('}');
+    }
+
+    json.push_str(r#"],"ties":["#);
+
+    for (idx, group) in explanation.ties.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+
+        json.push('[');
+
+        for (idx, cards) in group.iter().enumerate() {
+            if idx > 0 {
+                json.push(',');
+            }
+
+            string(&mut json, cards);
+        }
+
+        json.push(']');
+    }
+
+    json.push_str("]}");
+
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc2023_05::Day05;
+    use aoc2023_07::{Day07, Game, Rules, TiePolicy};
+    use aoc2023_common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_render_bench() {
+        let stats = |ns| bench::Stats {
+            min: Duration::from_nanos(ns),
+            median: Duration::from_nanos(ns * 2),
+            p95: Duration::from_nanos(ns * 3),
+        };
+
+        let report = bench::Report {
+            day: 5,
+            parse: stats(1),
+            part_one: stats(10),
+            part_two: stats(100),
+        };
+
+        assert_eq!(
+            concat!(
+                r#"{"warmup":3,"iterations":20,"days":[{"day":5,"#,
+                r#""parse":{"min_ns":1,"median_ns":2,"p95_ns":3},"#,
+                r#""part_one":{"min_ns":10,"median_ns":20,"p95_ns":30},"#,
+                r#""part_two":{"min_ns":100,"median_ns":200,"p95_ns":300}}]}"#
+            ),
+            render_bench(&bench::Config::default(), &[report])
+        );
+    }
+
+    #[test]
+    fn test_render_trace() {
+        let input = "seeds: 79 1
+
+seed-to-soil map:
+52 50 48
+
+soil-to-location map:
+0 15 37";
+
+        let almanac = Day05::parse(input).unwrap();
+
+        assert_eq!(
+            concat!(
+                r#"{"seed":79,"location":81,"hops":["#,
+                r#"{"map":"seed-to-soil","category":"soil","value":81,"line":4,"instruction":"52 50 48"},"#,
+                r#"{"map":"soil-to-location","category":"location","value":81,"line":null,"instruction":null}]}"#
+            ),
+            render_trace(&almanac.trace(79))
+        );
+    }
+
+    #[test]
+    fn test_render_explanation() {
+        let (_, game) = Day07::parse("KTJJT 220\nQQQJA 483\n32T3K 765").unwrap();
+
+        assert_eq!(
+            concat!(
+                r#"{"total_winnings":2391,"hands":["#,
+                r#"{"rank":1,"cards":"32T3K","strongest_hand":"32T3K","type":"one pair","signature":[2,1,1,1],"#,
+                r#""bid":765,"winnings":765,"below":null,"above":{"by":"type"}},"#,
+                r#"{"rank":2,"cards":"QQQJA","strongest_hand":"QQQQA","type":"four of a kind","signature":[4,1],"#,
+                r#""bid":483,"winnings":966,"below":{"by":"type"},"above":{"by":"card","position":1}},"#,
+                r#"{"rank":3,"cards":"KTJJT","strongest_hand":"KTTTT","type":"four of a kind","signature":[4,1],"#,
+                r#""bid":220,"winnings":660,"below":{"by":"card","position":1},"above":null}],"ties":[]}"#
+            ),
+            render_explanation(&game.explain())
+        );
+
+        let lines = [("KTJJT", "1"), ("KTJJT", "2")];
+        let game = Game::parse_with(&lines, Rules::jokers(), TiePolicy::SharedRank).unwrap();
+
+        assert!(render_explanation(&game.explain()).ends_with(r#"],"ties":[["KTJJT","KTJJT"]]}"#));
+    }
+
+    #[test]
+    fn test_string() {
+        let mut json = String::new();
+        string(&mut json, "a\"b\\c\n");
+
+        assert_eq!(r#""a\"b\\c\u000a""#, json);
+    }
+}
@@ -0,0 +1,279 @@
+mod cli;
+mod days;
+mod json;
+mod table;
+mod verify;
+
+use std::{fs, path::Path, process::ExitCode, time::Instant};
+
+use aoc2023_05::Day05;
+use aoc2023_06::{Boat, Day06};
+use aoc2023_07::{Day07, Game, Rules, TiePolicy};
+use aoc2023_common::{
+    answers, bench,
+    input::{self, InputError, Source},
+    parse, Part, Solution,
+};
+use cli::{Command, Target};
+use days::Day;
+use table::Row;
+
+/// Loads the input of `day`. When running every day, days without an input
+/// are skipped with a warning rather than failing the whole run.
+fn load_input(day: &Day, source: &Source, target: &Target) -> Result<Option<String>, String> {
+    match input::load(day.number, source) {
+        Ok(input) => Ok(Some(input)),
+        Err(err @ InputError::Missing { .. }) if *target == Target::All => {
+            eprintln!("warning: skipping day {:02}, {err}", day.number);
+
+            Ok(None)
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+fn run(target: Target, part: Option<Part>, source: Source) -> Result<String, String> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut rows = vec![];
+
+    for day in days::select(&target)? {
+        let Some(input) = load_input(day, &source, &target)? else {
+            continue;
+        };
+
+        for part in parts.iter().copied() {
+            let start = Instant::now();
+            let answer = day.solve(part, &input).map_err(|err| err.to_string())?;
+            let elapsed = start.elapsed();
+
+            rows.push(Row {
+                day: day.number,
+                part,
+                answer,
+                elapsed,
+            });
+        }
+    }
+
+    Ok(table::render(&rows))
+}
+
+fn run_bench(
+    target: Target,
+    source: Source,
+    config: bench::Config,
+    json: Option<&Path>,
+) -> Result<String, String> {
+    let mut reports = vec![];
+
+    for day in days::select(&target)? {
+        let Some(input) = load_input(day, &source, &target)? else {
+            continue;
+        };
+
+        reports.push(day.bench(&input, &config).map_err(|err| err.to_string())?);
+    }
+
+    let rendered = json::render_bench(&config, &reports);
+
+    match json {
+        Some(path) if path == Path::new("-") => Ok(rendered),
+        Some(path) => {
+            fs::write(path, rendered + "\n")
+                .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+
+            Ok(table::render_bench(&reports))
+        }
+        None => Ok(table::render_bench(&reports)),
+    }
+}
+
+/// Returns the report and whether every golden answer matched.
+fn run_verify(
+    target: Target,
+    part: Option<Part>,
+    answers_path: &Path,
+    record: bool,
+) -> Result<(String, bool), String> {
+    let answers_file = fs::read_to_string(answers_path)
+        .map_err(|err| format!("cannot read {}: {err}", answers_path.display()))?;
+    let mut expected = answers::parse(&answers_file).map_err(|err| {
+        format!(
+            "{}: {}",
+            answers_path.display(),
+            err.to_string().trim_start_matches("day 00, ")
+        )
+    })?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut checks = vec![];
+    let mut recorded = 0;
+
+    for day in days::select(&target)? {
+        let day_checks = match input::load(day.number, &Source::Default) {
+            Ok(input) => {
+                let day_checks = verify::verify_day(day, &parts, &input, &expected);
+
+                if record {
+                    recorded += verify::record(day, &day_checks, &input, &mut expected)?;
+                }
+
+                day_checks
+            }
+            Err(err) => verify::without_input(day, &parts, &expected, &err),
+        };
+
+        checks.extend(day_checks);
+    }
+
+    let mut report = table::render_verify(&checks);
+
+    if recorded > 0 {
+        let answers_file = expected
+            .iter()
+            .map(|expected| format!("{expected}\n"))
+            .collect::<String>();
+
+        fs::write(
+            answers_path,
+            format!("# day part input-hash answer\n{answers_file}"),
+        )
+        .map_err(|err| format!("cannot write {}: {err}", answers_path.display()))?;
+
+        report += &format!(
+            "\nrecorded {recorded} answers in {}",
+            answers_path.display()
+        );
+    }
+
+    let success = !checks.iter().any(|check| check.status.is_failure());
+
+    Ok((report, success))
+}
+
+fn run_trace(seed: u128, source: Source, json: bool) -> Result<String, String> {
+    let input = input::load(Day05::DAY, &source).map_err(|err| err.to_string())?;
+    let almanac = Day05::parse(&input).map_err(|err| err.locate(Day05::DAY, &input).to_string())?;
+    let trace = almanac.trace(seed);
+
+    if json {
+        Ok(json::render_trace(&trace))
+    } else {
+        Ok(trace.to_string())
+    }
+}
+
+fn run_races(source: Source) -> Result<String, String> {
+    let input = input::load(Day06::DAY, &source).map_err(|err| err.to_string())?;
+    let sheet = Day06::parse(&input).map_err(|err| err.locate(Day06::DAY, &input).to_string())?;
+    let boat = Boat::default();
+
+    let mut analyses = sheet
+        .races()
+        .iter()
+        .enumerate()
+        .map(|(idx, race)| ((idx + 1).to_string(), race.analyse(&boat)))
+        .collect::<Vec<_>>();
+
+    analyses.push(("kerned".to_string(), sheet.kerned_race().analyse(&boat)));
+
+    Ok(table::render_races(&analyses))
+}
+
+fn run_explain(part: Part, ties: TiePolicy, source: Source, json: bool) -> Result<String, String> {
+    let input = input::load(Day07::DAY, &source).map_err(|err| err.to_string())?;
+    let rules = match part {
+        Part::One => Rules::standard(),
+        Part::Two => Rules::jokers(),
+    };
+    let game = input
+        .lines()
+        .map(|line| parse::split_once(line, " "))
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(|lines| Game::parse_with(&lines, rules, ties))
+        .map_err(|err| err.locate(Day07::DAY, &input).to_string())?;
+
+    let explanation = game.explain();
+
+    if json {
+        Ok(json::render_explanation(&explanation))
+    } else {
+        Ok(explanation.to_string())
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match cli::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Help => Ok((cli::USAGE.to_string(), true)),
+        Command::Run {
+            target,
+            part,
+            input,
+        } => run(target, part, input.unwrap_or(Source::Default)).map(|output| (output, true)),
+        Command::Bench {
+            target,
+            input,
+            config,
+            json,
+        } => run_bench(
+            target,
+            input.unwrap_or(Source::Default),
+            config,
+            json.as_deref(),
+        )
+        .map(|output| (output, true)),
+        Command::Verify {
+            target,
+            part,
+            answers,
+            record,
+        } => run_verify(target, part, &answers, record),
+        Command::Trace { seed, input, json } => {
+            run_trace(seed, input.unwrap_or(Source::Default), json).map(|output| (output, true))
+        }
+        Command::Races { input } => {
+            run_races(input.unwrap_or(Source::Default)).map(|output| (output, true))
+        }
+        Command::Explain {
+            part,
+            ties,
+            input,
+            json,
+        } => run_explain(part, ties, input.unwrap_or(Source::Default), json)
+            .map(|output| (output, true)),
+    };
+
+    match result {
+        Ok((output, success)) => {
+            println!("{output}");
+
+            if success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::{fmt::Write, time::Duration};
+
+use aoc2023_06::Analysis;
+use aoc2023_common::{answers::Outcome, bench, Answer, Part};
+
+use crate::verify::{Check, Status};
+
+#[derive(Debug)]
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub fn render(rows: &[Row]) -> String {
+    let answer_width = rows
+        .iter()
+        .map(|row| row.answer.to_string().len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = String::new();
+
+    // unwrap: Writing into a String never fails.
+    writeln!(table, "Day  Part  {:<answer_width$}  Time", "Answer").unwrap();
+
+    for row in rows {
+        writeln!(
+            table,
+            "{:02}   {}     {:<answer_width$}  {:.2?}",
+            row.day, row.part, row.answer, row.elapsed
+        )
+        .unwrap();
+    }
+
+    let total: Duration = rows.iter().map(|row| row.elapsed).sum();
+
+    write!(
+        table,
+        "Total{:>pad$}  {total:.2?}",
+        "",
+        pad = answer_width + 6
+    )
+    .unwrap();
+
+    table
+}
+
+pub fn render_bench(reports: &[bench::Report]) -> String {
+    let mut table = String::new();
+
+    writeln!(
+        table,
+        "Day  Stage   {:>10}  {:>10}  {:>10}",
+        "Min", "Median", "p95"
+    )
+    .unwrap();
+
+    for report in reports {
+        let stages = [
+            ("parse", report.parse),
+            ("part 1", report.part_one),
+            ("part 2", report.part_two),
+        ];
+
+        for (stage, stats) in stages {
+            writeln!(
+                table,
+                "{:02}   {stage:<6}  {:>10}  {:>10}  {:>10}",
+                report.day,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95),
+            )
+            .unwrap();
+        }
+    }
+
+    table.truncate(table.trim_end().len());
+
+    table
+}
+
+pub fn render_verify(checks: &[Check]) -> String {
+    let mut table = String::new();
+
+    writeln!(table, "Day  Part  Status").unwrap();
+
+    for check in checks {
+        let status = match &check.status {
+            Status::Checked(Outcome::Match) => "ok".to_string(),
+            Status::Checked(Outcome::Mismatch { expected, actual }) => {
+                format!("MISMATCH: expected {expected}, got {actual}")
+            }
+            Status::Checked(Outcome::InputChanged { expected, actual }) => {
+                format!("INPUT CHANGED: recorded for {expected:016x}, input is {actual:016x}")
+            }
+            Status::Unrecorded => "no golden answer".to_string(),
+            Status::Skipped(reason) => format!("skipped, {reason}"),
+            Status::Failed(err) => format!("FAILED: {err}"),
+        };
+
+        writeln!(table, "{:02}   {}     {status}", check.day, check.part).unwrap();
+    }
+
+    let failures = checks
+        .iter()
+        .filter(|check| check.status.is_failure())
+        .count();
+
+    let skipped = checks
+        .iter()
+        .filter(|check| matches!(check.status, Status::Skipped(_)))
+        .count();
+
+    write!(table, "{} parts, {failures} failed", checks.len()).unwrap();
+
+    if skipped > 0 {
+        write!(table, ", {skipped} skipped").unwrap();
+    }
+
+    table
+}
+
+/// Day 06 race analyses, one labelled row per race.
+pub fn render_races(analyses: &[(String, Analysis)]) -> String {
+    let header = [
+        "Race",
+        "Time",
+        "Record",
+        "Window",
+        "Ways",
+        "Best hold",
+        "Distance",
+        "Margin",
+    ];
+
+    let rows = analyses
+        .iter()
+        .map(|(label, analysis)| {
+            let window = analysis.window.as_ref().map_or("-".to_string(), |window| {
+                format!("{}..={}", window.start(), window.end())
+            });
+
+            [
+                label.clone(),
+                analysis.race.duration_ms().to_string(),
+                analysis.race.record_distance_mm().to_string(),
+                window,
+                analysis.ways_to_win().to_string(),
+                analysis.optimal_hold_ms.to_string(),
+                analysis.max_distance_mm.to_string(),
+                analysis.margin_mm.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let widths = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([header[column].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+
+    for (column, name) in header.iter().enumerate() {
+        let width = widths[column];
+
+        match column {
+            0 => write!(table, "{name:<width$}").unwrap(),
+            _ => write!(table, "  {name:>width$}").unwrap(),
+        }
+    }
+
+    for row in rows.iter() {
+        table.push('\n');
+
+        for (column, cell) in row.iter().enumerate() {
+            let width = widths[column];
+
+            match column {
+                0 => write!(table, "{cell:<width$}").unwrap(),
+                _ => write!(table, "  {cell:>width$}").unwrap(),
+            }
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_06::{Boat, Race};
+
+    use super::*;
+
+    #[test]
+    fn test_render_verify() {
+        let check = |day, part, status| Check { day, part, status };
+        let checks = [
+            check(1, Part::One, Status::Checked(Outcome::Match)),
+            check(3, Part::One, Status::Skipped("no input".to_string())),
+            check(8, Part::Two, Status::Unrecorded),
+        ];
+
+        assert_eq!(
+            "Day  Part  Status
+01   1     ok
+03   1     skipped, no input
+08   2     no golden answer
+3 parts, 0 failed, 1 skipped",
+            render_verify(&checks)
+        );
+    }
+
+    #[test]
+    fn test_render_races() {
+        let analyses = [
+            ("1".to_string(), Race::new(7, 9).analyse(&Boat::default())),
+            ("2".to_string(), Race::new(7, 12).analyse(&Boat::default())),
+            (
+                "all".to_string(),
+                Race::new(30, 200).analyse(&Boat::default()),
+            ),
+        ];
+
+        assert_eq!(
+            "\
+Race  Time  Record   Window  Ways  Best hold  Distance  Margin
+1        7       9    2..=5     4          3        12       3
+2        7      12        -     0          3        12       0
+all     30     200  11..=19     9         15       225      25",
+            render_races(&analyses)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use aoc2023_common::{
+    answers::{self, Expected, Outcome},
+    input::InputError,
+    Part,
+};
+
+use crate::days::Day;
+
+#[derive(Debug)]
+pub enum Status {
+    Checked(Outcome),
+    /// There is no golden answer for this part yet, so it was not run.
+    Unrecorded,
+    /// There is neither an input nor a golden answer for this part, so there
+    /// is nothing to check or record.
+    Skipped(String),
+    Failed(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        !matches!(
+            self,
+            Self::Checked(Outcome::Match) | Self::Unrecorded | Self::Skipped(_)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+fn find(expected: &[Expected], day: u8, part: Part) -> Option<&Expected> {
+    expected
+        .iter()
+        .find(|expected| expected.day == day && expected.part == part)
+}
+
+/// Checks `parts` of `day`, run on `input`, against their golden answers.
+pub fn verify_day(day: &Day, parts: &[Part], input: &str, expected: &[Expected]) -> Vec<Check> {
+    parts
+        .iter()
+        .map(|part| {
+            let status = match find(expected, day.number, *part) {
+                None => Status::Unrecorded,
+                Some(expected) => expected
+                    .check(input, || day.solve(*part, input))
+                    .map_or_else(|err| Status::Failed(err.to_string()), Status::Checked),
+            };
+
+            Check {
+                day: day.number,
+                part: *part,
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Checks for a day whose input could not be loaded. That is only a failure
+/// for the parts that have a golden answer to check; the others are skipped.
+pub fn without_input(
+    day: &Day,
+    parts: &[Part],
+    expected: &[Expected],
+    err: &InputError,
+) -> Vec<Check> {
+    parts
+        .iter()
+        .map(|part| Check {
+            day: day.number,
+            part: *part,
+            status: match find(expected, day.number, *part) {
+                None => Status::Skipped(err.to_string()),
+                Some(_) => Status::Failed(err.to_string()),
+            },
+        })
+        .collect()
+}
+
+/// Records the current answer of every part in `checks` that is unrecorded or
+/// whose input changed. Mismatches are left alone: those need a human to look.
+pub fn record(
+    day: &Day,
+    checks: &[Check],
+    input: &str,
+    expected: &mut Vec<Expected>,
+) -> Result<usize, String> {
+    let mut recorded = 0;
+
+    for check in checks {
+        if !matches!(
+            check.status,
+            Status::Unrecorded | Status::Checked(Outcome::InputChanged { .. })
+        ) {
+            continue;
+        }
+
+        let answer = day
+            .solve(check.part, input)
+            .map_err(|err| err.to_string())?;
+
+        expected.retain(|expected| !(expected.day == check.day && expected.part == check.part));
+        expected.push(Expected {
+            day: check.day,
+            part: check.part,
+            input_hash: answers::input_hash(input),
+            answer: answer.to_string(),
+        });
+
+        recorded += 1;
+    }
+
+    expected.sort_by_key(|expected| (expected.day, expected.part));
+
+    Ok(recorded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc2023_common::input;
+
+    use super::*;
+    use crate::days;
+
+    // Parts without a golden answer, because day 03 has no real input checked in.
+    static SKIPPED: &[(u8, Part)] = &[(3, Part::One), (3, Part::Two)];
+
+    #[test]
+    fn test_real_inputs() {
+        let expected = answers::parse(include_str!("../../answers.txt")).unwrap();
+        let input_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+
+        for day in days::DAYS {
+            let checks = match fs::read_to_string(input::day_path(input_dir, day.number)) {
+                Ok(input) => verify_day(day, &Part::ALL, &input, &expected),
+                Err(err) => without_input(
+                    day,
+                    &Part::ALL,
+                    &expected,
+                    &InputError::Unreadable {
+                        path: input::day_path(input_dir, day.number),
+                        source: err,
+                    },
+                ),
+            };
+
+            for check in checks {
+                match check.status {
+                    Status::Checked(Outcome::Match) => {}
+                    Status::Skipped(_) if SKIPPED.contains(&(check.day, check.part)) => {}
+                    status => panic!("day {:02} part {}: {status:?}", check.day, check.part),
+                }
+            }
+        }
+    }
+}